@@ -0,0 +1,277 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::sysvar::instructions::{
+    self, load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::rwa_marketplace::RealEstateProperty;
+
+// Domain prefix of the message the auditor signs, followed by the root and the audit epoch
+pub const AUDIT_MESSAGE_PREFIX: &[u8] = b"establo-audit";
+
+// Layout of the ed25519 signature-verify instruction data
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+const ED25519_PUBKEY_LEN: usize = 32;
+
+// Create the audit registry and register the auditor key
+pub fn initialize_audit_registry(ctx: Context<InitializeAuditRegistry>, auditor: Pubkey) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.config.admin,
+        AuditError::Unauthorized
+    );
+
+    let audit_registry = &mut ctx.accounts.audit_registry;
+    audit_registry.auditor = auditor;
+    audit_registry.merkle_root = [0u8; 32];
+    audit_registry.audit_epoch = 0;
+    audit_registry.posted_at = 0;
+
+    // Emit event
+    emit!(AuditorUpdatedEvent {
+        old_auditor: Pubkey::default(),
+        auditor,
+    });
+
+    Ok(())
+}
+
+// Replace the registered auditor key
+pub fn set_auditor(ctx: Context<SetAuditor>, auditor: Pubkey) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.config.admin,
+        AuditError::Unauthorized
+    );
+
+    let audit_registry = &mut ctx.accounts.audit_registry;
+    let old_auditor = audit_registry.auditor;
+    audit_registry.auditor = auditor;
+
+    // Emit event
+    emit!(AuditorUpdatedEvent {
+        old_auditor,
+        auditor,
+    });
+
+    Ok(())
+}
+
+// Post the Merkle root of audited property valuations
+// The instruction right before this one must be an ed25519 verification of the auditor's
+// signature over `audit_message(merkle_root, audit_epoch)`; anyone may submit it
+pub fn post_audit_root(
+    ctx: Context<PostAuditRoot>,
+    merkle_root: [u8; 32],
+    audit_epoch: u64,
+) -> Result<()> {
+    let audit_registry = &mut ctx.accounts.audit_registry;
+    require!(
+        audit_epoch > audit_registry.audit_epoch,
+        AuditError::StaleAuditEpoch
+    );
+
+    // Find the signature verification the runtime already checked
+    let instructions_sysvar = ctx.accounts.instructions.to_account_info();
+    let current_index = load_current_index_checked(&instructions_sysvar)?;
+    require!(current_index > 0, AuditError::MissingAuditorSignature);
+    let ed25519_ix = load_instruction_at_checked(current_index as usize - 1, &instructions_sysvar)?;
+    require_keys_eq!(
+        ed25519_ix.program_id,
+        ed25519_program::ID,
+        AuditError::MissingAuditorSignature
+    );
+
+    let (signer, message) = parse_ed25519_instruction(&ed25519_ix.data)?;
+    require_keys_eq!(signer, audit_registry.auditor, AuditError::InvalidAuditorSignature);
+    require!(
+        message == audit_message(&merkle_root, audit_epoch),
+        AuditError::InvalidAuditorSignature
+    );
+
+    audit_registry.merkle_root = merkle_root;
+    audit_registry.audit_epoch = audit_epoch;
+    audit_registry.posted_at = Clock::get()?.unix_timestamp;
+
+    // Emit event
+    emit!(AuditRootPostedEvent {
+        auditor: signer,
+        merkle_root,
+        audit_epoch,
+    });
+
+    Ok(())
+}
+
+// Prove that a property's recorded value is included in the latest audited root
+pub fn prove_property_value(ctx: Context<ProvePropertyValue>, proof: Vec<[u8; 32]>) -> Result<()> {
+    let audit_registry = &ctx.accounts.audit_registry;
+    require!(audit_registry.audit_epoch > 0, AuditError::NoAuditRoot);
+
+    let property = &ctx.accounts.property;
+    let leaf = audit_leaf(&property.mint, property.value);
+    require!(
+        verify_merkle_proof(&proof, &audit_registry.merkle_root, leaf),
+        AuditError::InvalidMerkleProof
+    );
+
+    // Emit event
+    emit!(PropertyValueVerifiedEvent {
+        mint: property.mint,
+        value: property.value,
+        audit_epoch: audit_registry.audit_epoch,
+    });
+
+    Ok(())
+}
+
+// Message the auditor signs for a root
+pub fn audit_message(merkle_root: &[u8; 32], audit_epoch: u64) -> Vec<u8> {
+    [AUDIT_MESSAGE_PREFIX, merkle_root.as_ref(), &audit_epoch.to_le_bytes()].concat()
+}
+
+// Merkle leaf for an audited property valuation
+// Leaves and nodes use distinct prefixes so a node can't be passed off as a leaf
+pub fn audit_leaf(mint: &Pubkey, value: u64) -> [u8; 32] {
+    hashv(&[&[0u8], mint.as_ref(), &value.to_le_bytes()]).to_bytes()
+}
+
+// Parent of two Merkle nodes; pairs are hashed in sorted order so proofs carry no directions
+pub fn audit_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if left <= right { (left, right) } else { (right, left) };
+    hashv(&[&[1u8], first, second]).to_bytes()
+}
+
+// Walk a proof from `leaf` up to the root
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf, |node, sibling| audit_node(&node, sibling));
+    computed == *root
+}
+
+// Helper function to read the signer and message of a single-signature ed25519 instruction
+// The signature, key and message must all live in that instruction's own data
+fn parse_ed25519_instruction(data: &[u8]) -> Result<(Pubkey, Vec<u8>)> {
+    require!(
+        data.len() >= ED25519_OFFSETS_START + ED25519_OFFSETS_LEN && data[0] == 1,
+        AuditError::InvalidAuditorSignature
+    );
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let offsets = ED25519_OFFSETS_START;
+    let signature_instruction_index = read_u16(offsets + 2);
+    let public_key_offset = read_u16(offsets + 4) as usize;
+    let public_key_instruction_index = read_u16(offsets + 6);
+    let message_data_offset = read_u16(offsets + 8) as usize;
+    let message_data_size = read_u16(offsets + 10) as usize;
+    let message_instruction_index = read_u16(offsets + 12);
+
+    // u16::MAX points at the ed25519 instruction itself
+    require!(
+        signature_instruction_index == u16::MAX
+            && public_key_instruction_index == u16::MAX
+            && message_instruction_index == u16::MAX,
+        AuditError::InvalidAuditorSignature
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + ED25519_PUBKEY_LEN)
+        .ok_or(AuditError::InvalidAuditorSignature)?;
+    let message = data
+        .get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(AuditError::InvalidAuditorSignature)?;
+
+    let mut signer = [0u8; ED25519_PUBKEY_LEN];
+    signer.copy_from_slice(public_key);
+    Ok((Pubkey::new_from_array(signer), message.to_vec()))
+}
+
+// Account and structure definitions
+#[account]
+pub struct AuditRegistry {
+    pub auditor: Pubkey,                  // Key whose signature is required on audit roots
+    pub merkle_root: [u8; 32],            // Root of the latest audited (mint, value) leaves
+    pub audit_epoch: u64,                 // Epoch of the latest root, zero before the first audit
+    pub posted_at: i64,                   // Timestamp the latest root was posted
+}
+
+#[derive(Accounts)]
+pub struct InitializeAuditRegistry<'info> {
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, crate::Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 32 + 32 + 8 + 8,
+        seeds = [b"audit_registry"],
+        bump
+    )]
+    pub audit_registry: Account<'info, AuditRegistry>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetAuditor<'info> {
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, crate::Config>,
+    #[account(mut, seeds = [b"audit_registry"], bump)]
+    pub audit_registry: Account<'info, AuditRegistry>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PostAuditRoot<'info> {
+    #[account(mut, seeds = [b"audit_registry"], bump)]
+    pub audit_registry: Account<'info, AuditRegistry>,
+    /// CHECK: Instructions sysvar, used to find the auditor's ed25519 verification
+    #[account(address = instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProvePropertyValue<'info> {
+    #[account(seeds = [b"audit_registry"], bump)]
+    pub audit_registry: Account<'info, AuditRegistry>,
+    #[account(seeds = [b"property", property.mint.as_ref()], bump)]
+    pub property: Account<'info, RealEstateProperty>,
+}
+
+// Events
+#[event]
+pub struct AuditorUpdatedEvent {
+    pub old_auditor: Pubkey,
+    pub auditor: Pubkey,
+}
+
+#[event]
+pub struct AuditRootPostedEvent {
+    pub auditor: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub audit_epoch: u64,
+}
+
+#[event]
+pub struct PropertyValueVerifiedEvent {
+    pub mint: Pubkey,
+    pub value: u64,
+    pub audit_epoch: u64,
+}
+
+#[error_code(offset = 6400)]
+pub enum AuditError {
+    #[msg("Unauthorized access")]
+    Unauthorized,
+    #[msg("Audit root must be preceded by the auditor's ed25519 signature verification")]
+    MissingAuditorSignature,
+    #[msg("Signature is not the auditor's signature over this root")]
+    InvalidAuditorSignature,
+    #[msg("Audit epoch must be newer than the latest audit")]
+    StaleAuditEpoch,
+    #[msg("No audit root has been posted")]
+    NoAuditRoot,
+    #[msg("Merkle proof does not match the audited root")]
+    InvalidMerkleProof,
+}
@@ -28,24 +28,3 @@ impl From<StablecoinError> for ProgramError {
         ProgramError::Custom(e as u32)
     }
 }
-
-#[derive(Error, Debug, Copy, Clone)]
-pub enum RWAMarketplaceError {
-    #[error("Unauthorized access")]
-    Unauthorized,
-    
-    #[error("Invalid token account")]
-    InvalidTokenAccount,
-    
-    #[error("Asset not eligible for liquidation")]
-    NotEligibleForLiquidation,
-    
-    #[error("Invalid liquidation threshold (must be between 1-100)")]
-    InvalidThreshold,
-}
-
-impl From<RWAMarketplaceError> for ProgramError {
-    fn from(e: RWAMarketplaceError) -> Self {
-        ProgramError::Custom(e as u32 + 100) // Offset to avoid collision with StablecoinError
-    }
-} 
\ No newline at end of file
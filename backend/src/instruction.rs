@@ -16,11 +16,17 @@ pub enum StablecoinInstruction {
     /// Initialize the stablecoin program
     /// Accounts:
     /// 0. `[writable]` Config PDA
-    /// 1. `[writable, signer]` Admin account
-    /// 2. `[writable]` Mint account
-    /// 3. `[]` System program
-    /// 4. `[]` Token program
-    /// 5. `[]` Rent sysvar
+    /// 1. `[writable]` Fee split PDA
+    /// 2. `[writable, signer]` Admin account
+    /// 3. `[writable]` Mint account
+    /// 4. `[]` Mint authority PDA
+    /// 5. `[]` Freeze authority PDA
+    /// 6. `[]` USDT price oracle account
+    /// 7. `[writable]` Revenue vault PDA
+    /// 8. `[]` Vault authority PDA
+    /// 9. `[]` System program
+    /// 10. `[]` Token program
+    /// 11. `[]` Rent sysvar
     Initialize {
         usdt_mint: Pubkey,
         dao_token_account: Pubkey,
@@ -45,7 +51,7 @@ pub enum StablecoinInstruction {
     /// 1. `[writable, signer]` Admin account
     /// 2. `[writable]` Mint account
     /// 3. `[writable]` Recipient token account
-    /// 4. `[writable, signer]` Mint authority
+    /// 4. `[]` Mint authority PDA
     /// 5. `[]` Token program
     Mint {
         amount: u64,
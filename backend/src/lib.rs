@@ -1,55 +1,272 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::Discriminator;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token::spl_token::instruction::AuthorityType;
 
 // Add all modules
 pub mod rwa_marketplace;
+pub mod multisig;
+pub mod roles;
+pub mod proof_of_reserves;
+pub mod audit;
+pub mod oracle;
+pub mod psm;
 pub mod error;
 pub mod instruction;
 pub mod processor;
 pub mod entrypoint;
 
 // Re-export for easier testing/client access
-pub use error::{StablecoinError, RWAMarketplaceError};
+// (StablecoinError is the Anchor error enum defined below)
+pub use rwa_marketplace::RWAMarketplaceError;
 pub use instruction::StablecoinInstruction;
+pub use multisig::PrivilegedAction;
+pub use roles::Role;
 
 declare_id!("2tPFAWN8KNcMfWyMQ2Y522dPptcbaZHYE8bM9y7NzZva");
 
+// Green Ecosystem DAO transfer fee limits (basis points)
+pub const DEFAULT_FEE_BPS: u16 = 50;    // 0.5% default fee
+pub const MAX_FEE_BPS: u16 = 500;       // 5% protocol maximum
+pub const MAX_FEE_TIERS: usize = 4;     // Maximum number of fee brackets
+pub const MAX_FEE_BENEFICIARIES: usize = 5; // Maximum number of SDG programs sharing the fee
+
+// Delay before a proposed DAO account change can be executed (seconds)
+pub const DEFAULT_DAO_TIMELOCK: i64 = 2 * 24 * 60 * 60;
+pub const MIN_DAO_TIMELOCK: i64 = 24 * 60 * 60;
+
+// Backing targets per leg, in basis points of outstanding supply (70% USDT, 30% real estate)
+// USDT must meet its target; the real estate share is a cap that USDT may fill in for
+pub const DEFAULT_TARGET_USDT_BPS: u16 = 7_000;
+pub const DEFAULT_TARGET_RE_BPS: u16 = 3_000;
+// Default bounds governance can move the targets within
+pub const DEFAULT_MIN_USDT_BPS: u16 = 5_000;
+pub const DEFAULT_MAX_USDT_BPS: u16 = 10_000;
+pub const DEFAULT_MIN_RE_BPS: u16 = 0;
+pub const DEFAULT_MAX_RE_BPS: u16 = 5_000;
+// Target changes are ramped in over at least this long (seconds)
+pub const MIN_COLLATERAL_RAMP_DURATION: i64 = 24 * 60 * 60;
+
+// USDT depeg guard: minting stops below this price (basis points of $1) or on a stale feed
+pub const DEFAULT_MIN_USDT_PRICE_BPS: u16 = 9_800;
+pub const DEFAULT_MAX_ORACLE_STALENESS: i64 = 60;
+
+// Length of the mint and redemption rate limit window (seconds)
+pub const DEFAULT_RATE_LIMIT_WINDOW: i64 = 24 * 60 * 60;
+
+// Minimum KYC level required to mint or redeem Establo against USDT
+pub const MIN_ATTESTATION_LEVEL: u8 = 1;
+
+// Pause flags; stablecoin operations live in `Config.paused`, marketplace operations in `Marketplace.paused`
+pub const PAUSE_MINT: u8 = 1 << 0;
+pub const PAUSE_REDEEM: u8 = 1 << 1;
+pub const PAUSE_TRANSFER: u8 = 1 << 2;
+pub const PAUSE_LIST_RWA: u8 = 1 << 3;
+pub const PAUSE_VALUATION: u8 = 1 << 4;
+pub const PAUSE_LIQUIDATION: u8 = 1 << 5;
+pub const STABLECOIN_PAUSE_FLAGS: u8 = PAUSE_MINT | PAUSE_REDEEM | PAUSE_TRANSFER;
+pub const MARKETPLACE_PAUSE_FLAGS: u8 = PAUSE_LIST_RWA | PAUSE_VALUATION | PAUSE_LIQUIDATION;
+
+// Account sizes, including the 8-byte discriminator
+pub const CONFIG_SPACE: usize = 8 + 32 + 32 + 32 + 32 + 1 + 8 + 8 + 8 + 8
+    + 2 + 8 + 4 + MAX_FEE_TIERS * (8 + 2)
+    + 8
+    + 32 + 8 + 8
+    + 32
+    + 1
+    + 8 + 8 + 8 + 8 + 8 + 8
+    + 8 + 32
+    + 32 + 2 + 8
+    + 2 + 2 + 2 + 2 + 8 + 8 + 2 + 2 + 2 + 2
+    + 8
+    + 2 + 2 + 8 + 8 + 8;
+pub const FEE_SPLIT_SPACE: usize = 8 + 4 + MAX_FEE_BENEFICIARIES * (32 + 2 + 8);
+// Config size allocated by the first release, upgraded in place by `migrate_config`
+pub const LEGACY_CONFIG_SPACE: usize = 8 + 32 + 32 + 32 + 32 + 1 + 8 + 8 + 8;
+
 // Single program module for the Establo program
 #[program]
 pub mod establo {
     use super::*;
+    use crate::audit;
+    use crate::multisig;
+    use crate::oracle;
+    use crate::proof_of_reserves;
+    use crate::psm;
+    use crate::roles;
     use crate::rwa_marketplace;
 
     // Initialize the stablecoin program with Green Ecosystem DAO for SDG initiatives
+    // The USDT price feed is required up front: minting is priced against it from the first deposit
     pub fn initialize(
         ctx: Context<Initialize>,
         usdt_mint: Pubkey,
         dao_token_account: Pubkey,  // Green Ecosystem DAO address for SDG causes
         decimals: u8,
     ) -> Result<()> {
-        let config = &mut ctx.accounts.config;
-        config.admin = ctx.accounts.admin.key();
-        config.usdt_mint = usdt_mint;
-        config.dao_token_account = dao_token_account;  // Green Ecosystem DAO address for SDG initiatives
-        config.dao_contributions = 0;  // Initialize green ecosystem contributions
-        config.usdt_reserve = 0;
-        config.real_estate_value = 0;
-        config.mint = ctx.accounts.mint.key();
-        config.decimals = decimals;
+        // USDT amounts are scaled by these decimals, so they must be the mint's own
+        require!(decimals == ctx.accounts.mint.decimals, StablecoinError::InvalidDecimals);
+        oracle::load_price_account(&ctx.accounts.usdt_oracle)?;
+        ctx.accounts.config.set_inner(default_config(
+            ctx.accounts.admin.key(),
+            usdt_mint,
+            dao_token_account,
+            ctx.accounts.mint.key(),
+            decimals,
+            ctx.accounts.mint.supply,
+            ctx.accounts.usdt_oracle.key(),
+        ));
+
+        // Hand mint authority to the PDA so Establo can only be minted through program logic
+        let cpi_accounts = token::SetAuthority {
+            account_or_mint: ctx.accounts.mint.to_account_info(),
+            current_authority: ctx.accounts.admin.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::set_authority(
+            cpi_ctx,
+            AuthorityType::MintTokens,
+            Some(ctx.accounts.mint_authority.key()),
+        )?;
+
+        // Hand freeze authority to its PDA too, when the admin holds it
+        if ctx.accounts.mint.freeze_authority == COption::Some(ctx.accounts.admin.key()) {
+            let cpi_accounts = token::SetAuthority {
+                account_or_mint: ctx.accounts.mint.to_account_info(),
+                current_authority: ctx.accounts.admin.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            token::set_authority(
+                cpi_ctx,
+                AuthorityType::FreezeAccount,
+                Some(ctx.accounts.freeze_authority.key()),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // Upgrade a config created by the first release to the current layout (admin)
+    // The legacy addresses and DAO contributions carry over; everything else starts at the
+    // `initialize` defaults, and the reserves are re-read from on-chain state at the next sync
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        let config_info = ctx.accounts.config.to_account_info();
+        let legacy: LegacyConfig =
+            read_legacy_account(&config_info, Config::discriminator(), LEGACY_CONFIG_SPACE)?;
+        require!(ctx.accounts.admin.key() == legacy.admin, StablecoinError::Unauthorized);
+        require_keys_eq!(ctx.accounts.mint.key(), legacy.mint, StablecoinError::InvalidMint);
+        require!(
+            legacy.decimals == ctx.accounts.mint.decimals,
+            StablecoinError::InvalidDecimals
+        );
+        oracle::load_price_account(&ctx.accounts.usdt_oracle)?;
+
+        let config = Config {
+            dao_contributions: legacy.dao_contributions,
+            ..default_config(
+                legacy.admin,
+                legacy.usdt_mint,
+                legacy.dao_token_account,
+                legacy.mint,
+                legacy.decimals,
+                ctx.accounts.mint.supply,
+                ctx.accounts.usdt_oracle.key(),
+            )
+        };
+        write_migrated_account(
+            &config_info,
+            &ctx.accounts.admin.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            CONFIG_SPACE,
+            &config,
+        )?;
+
+        // Emit event
+        emit!(ConfigMigratedEvent {
+            admin: config.admin,
+            total_supply: config.total_supply,
+            dao_contributions: config.dao_contributions,
+            legacy_usdt_reserve: legacy.usdt_reserve,
+            legacy_real_estate_value: legacy.real_estate_value,
+        });
+
+        Ok(())
+    }
+
+    // Move mint authority of an already-deployed mint from an external key to the PDA
+    pub fn migrate_mint_authority(ctx: Context<MigrateMintAuthority>) -> Result<()> {
+        let config = &ctx.accounts.config;
+        require!(ctx.accounts.admin.key() == config.admin, StablecoinError::Unauthorized);
+
+        let cpi_accounts = token::SetAuthority {
+            account_or_mint: ctx.accounts.mint.to_account_info(),
+            current_authority: ctx.accounts.current_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::set_authority(
+            cpi_ctx,
+            AuthorityType::MintTokens,
+            Some(ctx.accounts.mint_authority.key()),
+        )?;
+
+        // Emit event
+        emit!(MintAuthorityMigratedEvent {
+            old_authority: ctx.accounts.current_authority.key(),
+            new_authority: ctx.accounts.mint_authority.key(),
+        });
+
+        Ok(())
+    }
+
+    // Move freeze authority of an already-deployed mint from an external key to the PDA
+    pub fn migrate_freeze_authority(ctx: Context<MigrateFreezeAuthority>) -> Result<()> {
+        let config = &ctx.accounts.config;
+        require!(ctx.accounts.admin.key() == config.admin, StablecoinError::Unauthorized);
+
+        let cpi_accounts = token::SetAuthority {
+            account_or_mint: ctx.accounts.mint.to_account_info(),
+            current_authority: ctx.accounts.current_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::set_authority(
+            cpi_ctx,
+            AuthorityType::FreezeAccount,
+            Some(ctx.accounts.freeze_authority.key()),
+        )?;
+
+        // Emit event
+        emit!(FreezeAuthorityMigratedEvent {
+            old_authority: ctx.accounts.current_authority.key(),
+            new_authority: ctx.accounts.freeze_authority.key(),
+        });
+
         Ok(())
     }
 
-    // Transfer tokens with a 0.5% fee to the Green Ecosystem DAO
+    // Transfer tokens with a fee to the Green Ecosystem DAO (0.5% by default)
     // This fee supports sustainable development goals (SDGs) like reforestation and climate initiatives
-    pub fn transfer(ctx: Context<StablecoinTransfer>, amount: u64) -> Result<()> {
+    pub fn transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, StablecoinTransfer<'info>>,
+        amount: u64,
+    ) -> Result<()> {
         let config = &ctx.accounts.config;
+        require!(config.paused & PAUSE_TRANSFER == 0, StablecoinError::Paused);
+        require!(
+            !pda_exists(&ctx.accounts.sender_blacklist)
+                && !pda_exists(&ctx.accounts.recipient_blacklist),
+            StablecoinError::Blacklisted
+        );
         
-        // Calculate fee (0.5% = 50 basis points) for green ecosystem initiatives
-        let fee = amount
-            .checked_mul(50)
-            .ok_or(StablecoinError::Overflow)?
-            .checked_div(10000)
-            .ok_or(StablecoinError::Overflow)?;
+        // Calculate fee from the configured schedule for green ecosystem initiatives
+        // Transfers to or from an allowlisted wallet carry no fee
+        let exempt = pda_exists(&ctx.accounts.sender_fee_exemption)
+            || pda_exists(&ctx.accounts.recipient_fee_exemption);
+        let fee = if exempt { 0 } else { transfer_fee(config, amount) };
         let amount_after_fee = amount
             .checked_sub(fee)
             .ok_or(StablecoinError::InsufficientAmount)?;
@@ -65,13 +282,67 @@ pub mod establo {
         token::transfer(cpi_ctx, amount_after_fee)?;
 
         // Transfer fee to Green Ecosystem DAO for SDG initiatives
-        let cpi_accounts_fee = Transfer {
-            from: ctx.accounts.sender_token_account.to_account_info(),
-            to: ctx.accounts.dao_token_account.to_account_info(),
-            authority: ctx.accounts.sender.to_account_info(),
-        };
-        let cpi_ctx_fee = CpiContext::new(cpi_program, cpi_accounts_fee);
-        token::transfer(cpi_ctx_fee, fee)?;
+        let beneficiaries = &mut ctx.accounts.fee_split.beneficiaries;
+        if fee > 0 && beneficiaries.is_empty() {
+            // No split configured, the whole fee goes to the DAO account
+            let cpi_accounts_fee = Transfer {
+                from: ctx.accounts.sender_token_account.to_account_info(),
+                to: ctx.accounts.dao_token_account.to_account_info(),
+                authority: ctx.accounts.sender.to_account_info(),
+            };
+            let cpi_ctx_fee = CpiContext::new(cpi_program, cpi_accounts_fee);
+            token::transfer(cpi_ctx_fee, fee)?;
+
+            emit!(FeeDistributedEvent {
+                token_account: ctx.accounts.dao_token_account.key(),
+                amount: fee,
+            });
+        } else if fee > 0 {
+            // Split the fee by weight; beneficiary token accounts are passed as remaining accounts
+            require!(
+                ctx.remaining_accounts.len() == beneficiaries.len(),
+                StablecoinError::InvalidFeeBeneficiary
+            );
+            let last = beneficiaries.len() - 1;
+            let mut undistributed = fee;
+            for (i, (beneficiary, beneficiary_token_account)) in beneficiaries
+                .iter_mut()
+                .zip(ctx.remaining_accounts.iter())
+                .enumerate()
+            {
+                require_keys_eq!(
+                    beneficiary_token_account.key(),
+                    beneficiary.token_account,
+                    StablecoinError::InvalidFeeBeneficiary
+                );
+
+                // The last beneficiary receives the rounding remainder
+                let share = if i == last {
+                    undistributed
+                } else {
+                    (fee as u128 * beneficiary.weight_bps as u128 / 10_000) as u64
+                };
+                undistributed -= share;
+
+                let cpi_accounts_fee = Transfer {
+                    from: ctx.accounts.sender_token_account.to_account_info(),
+                    to: beneficiary_token_account.clone(),
+                    authority: ctx.accounts.sender.to_account_info(),
+                };
+                let cpi_ctx_fee = CpiContext::new(cpi_program.clone(), cpi_accounts_fee);
+                token::transfer(cpi_ctx_fee, share)?;
+
+                beneficiary.contributions = beneficiary
+                    .contributions
+                    .checked_add(share)
+                    .ok_or(StablecoinError::Overflow)?;
+
+                emit!(FeeDistributedEvent {
+                    token_account: beneficiary.token_account,
+                    amount: share,
+                });
+            }
+        }
 
         // Update Green Ecosystem DAO contributions for SDG initiatives
         let config = &mut ctx.accounts.config;
@@ -81,308 +352,2171 @@ pub mod establo {
             .ok_or(StablecoinError::Overflow)?;
 
         // Emit event with green ecosystem fee details
+        // Where the fee went is reported per recipient by the FeeDistributedEvents above
         emit!(TransferEvent {
             from: ctx.accounts.sender.key(),
             to: ctx.accounts.recipient_token_account.owner,
             amount: amount_after_fee,
             fee,  // Fee for green ecosystem initiatives
-            dao: config.dao_token_account,  // Green Ecosystem DAO receiving the fee
         });
 
         Ok(())
     }
 
-    // Mint new tokens
-    pub fn mint(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
+    // Update the Green Ecosystem DAO fee schedule
+    pub fn set_fee(
+        ctx: Context<SetFee>,
+        fee_bps: u16,
+        min_fee: u64,
+        fee_tiers: Vec<FeeTier>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(fee_bps <= MAX_FEE_BPS, StablecoinError::InvalidFee);
+        require!(fee_tiers.len() <= MAX_FEE_TIERS, StablecoinError::InvalidFeeTiers);
+        // The floor may not exceed one whole token
+        let one_token = 10u64
+            .checked_pow(config.decimals as u32)
+            .ok_or(StablecoinError::Overflow)?;
+        require!(min_fee <= one_token, StablecoinError::InvalidFee);
+
+        // Brackets must be sorted by strictly increasing size and respect the maximum
+        for (i, tier) in fee_tiers.iter().enumerate() {
+            require!(tier.fee_bps <= MAX_FEE_BPS, StablecoinError::InvalidFee);
+            if i > 0 {
+                require!(
+                    tier.min_amount > fee_tiers[i - 1].min_amount,
+                    StablecoinError::InvalidFeeTiers
+                );
+            }
+        }
+
+        let old_fee_bps = config.fee_bps;
+        config.fee_bps = fee_bps;
+        config.min_fee = min_fee;
+        config.fee_tiers = fee_tiers.clone();
+
+        // Emit event
+        emit!(FeeUpdatedEvent {
+            old_fee_bps,
+            fee_bps,
+            min_fee,
+            fee_tiers,
+        });
+
+        Ok(())
+    }
+
+    // Update the protocol mint and redeem fees, collected into the revenue vault rather than the DAO
+    pub fn set_mint_redeem_fees(
+        ctx: Context<SetFee>,
+        mint_fee_bps: u16,
+        redeem_fee_bps: u16,
+    ) -> Result<()> {
+        require!(
+            mint_fee_bps <= MAX_FEE_BPS && redeem_fee_bps <= MAX_FEE_BPS,
+            StablecoinError::InvalidFee
+        );
+
+        let config = &mut ctx.accounts.config;
+        let old_mint_fee_bps = config.mint_fee_bps;
+        let old_redeem_fee_bps = config.redeem_fee_bps;
+        config.mint_fee_bps = mint_fee_bps;
+        config.redeem_fee_bps = redeem_fee_bps;
+
+        // Emit event
+        emit!(MintRedeemFeesUpdatedEvent {
+            old_mint_fee_bps,
+            mint_fee_bps,
+            old_redeem_fee_bps,
+            redeem_fee_bps,
+        });
+
+        Ok(())
+    }
+
+    // Configure how the Green Ecosystem DAO fee is split across SDG programs
+    // Beneficiary token accounts are passed as remaining accounts, in the same order
+    pub fn set_fee_split(
+        ctx: Context<SetFeeSplit>,
+        beneficiaries: Vec<FeeBeneficiaryWeight>,
+    ) -> Result<()> {
         let config = &ctx.accounts.config;
-        require!(ctx.accounts.admin.key() == config.admin, StablecoinError::Unauthorized);
-        require!(is_backed(config, amount), StablecoinError::InsufficientReserves);
+        require!(
+            beneficiaries.len() <= MAX_FEE_BENEFICIARIES,
+            StablecoinError::InvalidFeeSplit
+        );
+        require!(
+            ctx.remaining_accounts.len() == beneficiaries.len(),
+            StablecoinError::InvalidFeeBeneficiary
+        );
 
-        // Mint tokens to recipient
-        let cpi_accounts = token::MintTo {
+        // Weights must cover the whole fee, unless the split is being cleared
+        let total_weight: u32 = beneficiaries.iter().map(|b| b.weight_bps as u32).sum();
+        require!(
+            beneficiaries.is_empty() || total_weight == 10_000,
+            StablecoinError::InvalidFeeSplit
+        );
+
+        let mut updated = Vec::with_capacity(beneficiaries.len());
+        for (beneficiary, info) in beneficiaries.iter().zip(ctx.remaining_accounts.iter()) {
+            require!(beneficiary.weight_bps > 0, StablecoinError::InvalidFeeSplit);
+            require_keys_eq!(
+                info.key(),
+                beneficiary.token_account,
+                StablecoinError::InvalidFeeBeneficiary
+            );
+            let token_account = Account::<TokenAccount>::try_from(info)?;
+            require!(token_account.mint == config.mint, StablecoinError::InvalidMint);
+
+            // Keep running totals for beneficiaries that stay in the split
+            let contributions = ctx
+                .accounts
+                .fee_split
+                .beneficiaries
+                .iter()
+                .find(|existing| existing.token_account == beneficiary.token_account)
+                .map(|existing| existing.contributions)
+                .unwrap_or(0);
+            updated.push(FeeBeneficiary {
+                token_account: beneficiary.token_account,
+                weight_bps: beneficiary.weight_bps,
+                contributions,
+            });
+        }
+        ctx.accounts.fee_split.beneficiaries = updated;
+
+        // Emit event
+        emit!(FeeSplitUpdatedEvent { beneficiaries });
+
+        Ok(())
+    }
+
+    // Exempt a wallet from the Green Ecosystem DAO transfer fee
+    pub fn add_fee_exemption(ctx: Context<AddFeeExemption>, wallet: Pubkey) -> Result<()> {
+        ctx.accounts.fee_exemption.wallet = wallet;
+
+        // Emit event
+        emit!(FeeExemptionUpdatedEvent {
+            wallet,
+            exempt: true,
+        });
+
+        Ok(())
+    }
+
+    // Remove a wallet's fee exemption
+    pub fn remove_fee_exemption(ctx: Context<RemoveFeeExemption>) -> Result<()> {
+        // Emit event
+        emit!(FeeExemptionUpdatedEvent {
+            wallet: ctx.accounts.fee_exemption.wallet,
+            exempt: false,
+        });
+
+        Ok(())
+    }
+
+    // Freeze a holder's Establo token account (Compliance)
+    pub fn freeze_account(ctx: Context<FreezeTokenAccount>) -> Result<()> {
+        let bump = *ctx.bumps.get("freeze_authority").unwrap();
+        let freeze_authority_seeds: &[&[u8]] = &[b"freeze_authority", &[bump]];
+        let signer = &[freeze_authority_seeds];
+        let cpi_accounts = token::FreezeAccount {
+            account: ctx.accounts.token_account.to_account_info(),
             mint: ctx.accounts.mint.to_account_info(),
-            to: ctx.accounts.recipient_token_account.to_account_info(),
-            authority: ctx.accounts.mint_authority.to_account_info(),
+            authority: ctx.accounts.freeze_authority.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token::mint_to(cpi_ctx, amount)?;
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::freeze_account(cpi_ctx)?;
 
         // Emit event
-        emit!(MintEvent {
-            to: ctx.accounts.recipient_token_account.owner,
-            amount,
+        emit!(TokenAccountFrozenEvent {
+            token_account: ctx.accounts.token_account.key(),
+            owner: ctx.accounts.token_account.owner,
+            compliance: ctx.accounts.compliance.key(),
         });
 
         Ok(())
     }
 
-    // Burn tokens
-    pub fn burn(ctx: Context<BurnTokens>, amount: u64) -> Result<()> {
-        let config = &ctx.accounts.config;
-        require!(ctx.accounts.admin.key() == config.admin, StablecoinError::Unauthorized);
-
-        // Burn tokens from account
-        let cpi_accounts = token::Burn {
+    // Thaw a frozen Establo token account (Compliance)
+    pub fn thaw_account(ctx: Context<FreezeTokenAccount>) -> Result<()> {
+        let bump = *ctx.bumps.get("freeze_authority").unwrap();
+        let freeze_authority_seeds: &[&[u8]] = &[b"freeze_authority", &[bump]];
+        let signer = &[freeze_authority_seeds];
+        let cpi_accounts = token::ThawAccount {
+            account: ctx.accounts.token_account.to_account_info(),
             mint: ctx.accounts.mint.to_account_info(),
-            from: ctx.accounts.token_account.to_account_info(),
-            authority: ctx.accounts.admin.to_account_info(),
+            authority: ctx.accounts.freeze_authority.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token::burn(cpi_ctx, amount)?;
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::thaw_account(cpi_ctx)?;
 
         // Emit event
-        emit!(BurnEvent {
-            from: ctx.accounts.token_account.owner,
-            amount,
+        emit!(TokenAccountThawedEvent {
+            token_account: ctx.accounts.token_account.key(),
+            owner: ctx.accounts.token_account.owner,
+            compliance: ctx.accounts.compliance.key(),
         });
 
         Ok(())
     }
 
-    // Get reserve status
-    pub fn get_reserves(ctx: Context<GetReserves>) -> Result<(u64, u64, bool)> {
-        let config = &ctx.accounts.config;
-        Ok((
-            config.usdt_reserve,
-            config.real_estate_value,
-            is_backed(config, 0), // Check if current supply is backed
-        ))
-    }
+    // Blacklist a wallet so it can neither send nor receive Establo, mint, redeem or use the PSM (Compliance)
+    pub fn add_to_blacklist(ctx: Context<AddToBlacklist>, wallet: Pubkey) -> Result<()> {
+        ctx.accounts.blacklist.wallet = wallet;
 
-    // PHASE 2 FEATURES - TO BE IMPLEMENTED
-    // The following features will be implemented in the next phase:
-    
-    // Update reserves (USDT and real estate)
-    /*
-    pub fn update_reserves(
-        ctx: Context<UpdateReserves>,
-        usdt_amount: u64,
-        real_estate_value: u64,
-    ) -> Result<()> {
-        let config = &mut ctx.accounts.config;
-        require!(ctx.accounts.admin.key() == config.admin, StablecoinError::Unauthorized);
+        // Emit event
+        emit!(BlacklistUpdatedEvent {
+            wallet,
+            blacklisted: true,
+            compliance: ctx.accounts.compliance.key(),
+        });
 
-        config.usdt_reserve = usdt_amount;
-        config.real_estate_value = real_estate_value;
+        Ok(())
+    }
 
+    // Remove a wallet from the blacklist (Compliance)
+    pub fn remove_from_blacklist(ctx: Context<RemoveFromBlacklist>) -> Result<()> {
         // Emit event
-        emit!(ReservesUpdatedEvent {
-            usdt_amount,
-            real_estate_value,
+        emit!(BlacklistUpdatedEvent {
+            wallet: ctx.accounts.blacklist.wallet,
+            blacklisted: false,
+            compliance: ctx.accounts.compliance.key(),
         });
 
         Ok(())
     }
-    */
 
-    // Update DAO token account for green ecosystem initiatives
-    /*
-    pub fn update_dao_account(ctx: Context<UpdateDao>, new_dao_account: Pubkey) -> Result<()> {
-        let config = &mut ctx.accounts.config;
-        require!(ctx.accounts.admin.key() == config.admin, StablecoinError::Unauthorized);
+    // Issue a KYC attestation for an onboarded wallet (Compliance)
+    // To change an attestation, revoke it and issue a new one
+    pub fn issue_attestation(
+        ctx: Context<IssueAttestation>,
+        wallet: Pubkey,
+        level: u8,
+        expiry: i64,
+        jurisdiction: [u8; 2],
+    ) -> Result<()> {
+        require!(level >= MIN_ATTESTATION_LEVEL, StablecoinError::InsufficientAttestationLevel);
         require!(
-            new_dao_account != Pubkey::default(),
-            StablecoinError::InvalidDaoAccount
+            expiry > Clock::get()?.unix_timestamp,
+            StablecoinError::AttestationExpired
         );
 
-        config.dao_token_account = new_dao_account;
+        let attestation = &mut ctx.accounts.attestation;
+        attestation.wallet = wallet;
+        attestation.level = level;
+        attestation.expiry = expiry;
+        attestation.jurisdiction = jurisdiction;
+        attestation.issuer = ctx.accounts.compliance.key();
 
         // Emit event
-        emit!(DaoUpdatedEvent { 
-            new_dao_account 
+        emit!(AttestationIssuedEvent {
+            wallet,
+            level,
+            expiry,
+            jurisdiction,
+            issuer: attestation.issuer,
         });
 
         Ok(())
     }
-    */
 
-    // Get DAO contributions to green ecosystem initiatives
-    /*
-    pub fn get_dao_contributions(ctx: Context<GetDaoContributions>) -> Result<u64> {
-        Ok(ctx.accounts.config.dao_contributions)
-    }
-    */
+    // Revoke a wallet's KYC attestation (Compliance)
+    pub fn revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+        // Emit event
+        emit!(AttestationRevokedEvent {
+            wallet: ctx.accounts.attestation.wallet,
+            compliance: ctx.accounts.compliance.key(),
+        });
 
-    // RWA Marketplace Core Functions - Minimal Implementation for MVP
-    // Initialize the RWA marketplace
-    pub fn initialize_marketplace(
-        ctx: Context<rwa_marketplace::Initialize>,
-        stablecoin_config_address: Pubkey
-    ) -> Result<()> {
-        rwa_marketplace::initialize(ctx, stablecoin_config_address)
+        Ok(())
     }
 
-    // List a new Real World Asset
-    pub fn list_rwa(
-        ctx: Context<rwa_marketplace::ListRWA>,
-        uri: String,
-        name: String,
-        symbol: String,
-        asset_value: u64,
-        location: String,
-        property_details: String,
-        liquidation_threshold: Option<u8>,
-    ) -> Result<()> {
-        rwa_marketplace::list_rwa(ctx, uri, name, symbol, asset_value, location, property_details, liquidation_threshold)
-    }
+    // Mint new tokens
+    // Requires an approved multisig proposal for this recipient and amount, executed by a Minter
+    pub fn mint(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
+        require!(ctx.accounts.config.paused & PAUSE_MINT == 0, StablecoinError::Paused);
+        require!(!pda_exists(&ctx.accounts.recipient_blacklist), StablecoinError::Blacklisted);
+        let usdt_price_bps = oracle::usdt_price_bps(&ctx.accounts.usdt_oracle, &ctx.accounts.config)?;
 
-    // PHASE 2 FEATURES - TO BE IMPLEMENTED
-    // The following RWA marketplace features will be implemented in the next phase:
+        let action = PrivilegedAction::Mint {
+            recipient_token_account: ctx.accounts.recipient_token_account.key(),
+            amount,
+        };
+        multisig::execute_proposal(&ctx.accounts.admin_set, &mut ctx.accounts.proposal, &action)?;
+        consume_mint_limit(&mut ctx.accounts.config, amount)?;
 
-    /*
-    // Update RWA valuation
-    pub fn update_valuation(
-        ctx: Context<rwa_marketplace::UpdateValuation>,
-        new_value: u64
-    ) -> Result<()> {
-        rwa_marketplace::update_valuation(ctx, new_value)
-    }
+        let config = &ctx.accounts.config;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            is_backed(config, usdt_price_bps, amount, now),
+            StablecoinError::InsufficientReserves
+        );
 
-    // Transfer RWA ownership
-    pub fn transfer_rwa(
-        ctx: Context<rwa_marketplace::TransferRWA>
-    ) -> Result<()> {
-        rwa_marketplace::transfer_rwa(ctx)
-    }
+        // Mint tokens to recipient, signed by the mint authority PDA
+        let bump = *ctx.bumps.get("mint_authority").unwrap();
+        let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[bump]];
+        let signer = &[mint_authority_seeds];
+        let cpi_accounts = token::MintTo {
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.recipient_token_account.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::mint_to(cpi_ctx, amount)?;
 
-    // Liquidate RWA
-    pub fn liquidate_rwa(
-        ctx: Context<rwa_marketplace::LiquidateRWA>
-    ) -> Result<()> {
-        rwa_marketplace::liquidate_rwa(ctx)
-    }
+        // Track outstanding supply
+        let config = &mut ctx.accounts.config;
+        config.total_supply = config
+            .total_supply
+            .checked_add(amount)
+            .ok_or(StablecoinError::Overflow)?;
 
-    // Set liquidation threshold for RWAs
-    pub fn set_liquidation_threshold(
-        ctx: Context<rwa_marketplace::SetLiquidationThreshold>,
-        threshold: u8
-    ) -> Result<()> {
-        rwa_marketplace::set_liquidation_threshold(ctx, threshold)
+        // Emit event
+        emit!(MintEvent {
+            to: ctx.accounts.recipient_token_account.owner,
+            amount,
+        });
+
+        Ok(())
     }
-    */
-}
 
-// Helper function to check if minting is backed
-fn is_backed(config: &Config, additional_amount: u64) -> bool {
-    // Get the current total supply (in a real implementation this would be fetched from the mint)
-    // For now, we're just checking the additional amount being minted
-    
-    // Calculate required backing amounts (70% USDT, 30% real estate)
-    let required_usdt = additional_amount
-        .checked_mul(70)
-        .and_then(|x| x.checked_div(100))
-        .unwrap_or(u64::MAX);
-    
-    let required_real_estate = additional_amount
-        .checked_mul(30)
-        .and_then(|x| x.checked_div(100))
-        .unwrap_or(u64::MAX);
-    
-    // Verify that reserves meet requirements
-    config.usdt_reserve >= required_usdt && config.real_estate_value >= required_real_estate
-}
+    // Deposit USDT into the reserve vault and mint Establo 1:1 (decimal-normalized)
+    // Open to any attested wallet: the minted amount is derived from the actual vault movement
+    // The mint fee is minted into the protocol revenue vault, the rest to the depositor
+    pub fn deposit_and_mint(ctx: Context<DepositAndMint>, usdt_amount: u64) -> Result<()> {
+        require!(ctx.accounts.config.paused & PAUSE_MINT == 0, StablecoinError::Paused);
+        require!(!pda_exists(&ctx.accounts.user_blacklist), StablecoinError::Blacklisted);
+        check_attestation(&ctx.accounts.attestation)?;
+        let usdt_price_bps = oracle::usdt_price_bps(&ctx.accounts.usdt_oracle, &ctx.accounts.config)?;
+        require!(usdt_amount > 0, StablecoinError::InvalidAmount);
 
-// Account structs
-#[account]
-pub struct Config {
-    pub admin: Pubkey,              // Admin authority
-    pub usdt_mint: Pubkey,          // USDT mint address
-    pub dao_token_account: Pubkey,  // Green Ecosystem DAO token account for SDG initiatives
-    pub mint: Pubkey,               // Stablecoin mint
-    pub decimals: u8,               // Token decimals
-    pub dao_contributions: u64,     // Total contributions to green ecosystem initiatives (SDGs)
-    pub usdt_reserve: u64,          // USDT reserve (70%)
-    pub real_estate_value: u64,     // Real estate value (30%)
-}
+        // Move USDT from the user into the program-owned reserve vault
+        let vault_balance_before = ctx.accounts.usdt_vault.amount;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.user_usdt_account.to_account_info(),
+            to: ctx.accounts.usdt_vault.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts);
+        token::transfer(cpi_ctx, usdt_amount)?;
+
+        ctx.accounts.usdt_vault.reload()?;
+        let deposited = ctx
+            .accounts
+            .usdt_vault
+            .amount
+            .checked_sub(vault_balance_before)
+            .ok_or(StablecoinError::Overflow)?;
+
+        // Convert the deposited USDT into Establo base units
+        let amount = normalize_amount(
+            deposited,
+            ctx.accounts.usdt_mint.decimals,
+            ctx.accounts.config.decimals,
+        )?;
+        require!(amount > 0, StablecoinError::InvalidAmount);
+
+        let config = &mut ctx.accounts.config;
+        consume_mint_limit(config, amount)?;
+        let fee = protocol_fee(amount, config.mint_fee_bps);
+        let amount_after_fee = amount - fee;
+        require!(amount_after_fee > 0, StablecoinError::InsufficientAmount);
+        config.mint_fee_revenue = config
+            .mint_fee_revenue
+            .checked_add(fee)
+            .ok_or(StablecoinError::Overflow)?;
+        config.usdt_reserve = config
+            .usdt_reserve
+            .checked_add(amount)
+            .ok_or(StablecoinError::Overflow)?;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            is_backed(config, usdt_price_bps, amount, now),
+            StablecoinError::InsufficientReserves
+        );
+        config.total_supply = config
+            .total_supply
+            .checked_add(amount)
+            .ok_or(StablecoinError::Overflow)?;
+
+        // Mint Establo to the depositor, signed by the mint authority PDA
+        let bump = *ctx.bumps.get("mint_authority").unwrap();
+        let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[bump]];
+        let signer = &[mint_authority_seeds];
+        let cpi_accounts = token::MintTo {
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer);
+        token::mint_to(cpi_ctx, amount_after_fee)?;
+
+        // Mint the fee into the protocol revenue vault
+        if fee > 0 {
+            let cpi_accounts = token::MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.revenue_vault.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            token::mint_to(cpi_ctx, fee)?;
+
+            // Emit event
+            emit!(MintFeeCollectedEvent {
+                payer: ctx.accounts.user.key(),
+                fee,
+                total: ctx.accounts.config.mint_fee_revenue,
+            });
+        }
+
+        // Emit event
+        emit!(MintEvent {
+            to: ctx.accounts.user_token_account.owner,
+            amount: amount_after_fee,
+        });
+
+        Ok(())
+    }
+
+    // Redeem Establo for USDT paid out of the reserve vault
+    // The redeem fee is kept in Establo in the protocol revenue vault, the rest is burned and paid out
+    pub fn redeem(ctx: Context<Redeem>, amount: u64) -> Result<()> {
+        require!(ctx.accounts.config.paused & PAUSE_REDEEM == 0, StablecoinError::Paused);
+        require!(!pda_exists(&ctx.accounts.holder_blacklist), StablecoinError::Blacklisted);
+        check_attestation(&ctx.accounts.attestation)?;
+        require!(amount > 0, StablecoinError::InvalidAmount);
+
+        let fee = protocol_fee(amount, ctx.accounts.config.redeem_fee_bps);
+        let amount_after_fee = amount - fee;
+
+        // Convert the redeemed Establo into USDT base units
+        let usdt_amount = normalize_amount(
+            amount_after_fee,
+            ctx.accounts.config.decimals,
+            ctx.accounts.usdt_mint.decimals,
+        )?;
+        require!(usdt_amount > 0, StablecoinError::InvalidAmount);
+        require!(
+            ctx.accounts.usdt_vault.amount >= usdt_amount,
+            StablecoinError::InsufficientReserves
+        );
+
+        // The window counts the gross amount, as deposits do, fee included
+        let config = &mut ctx.accounts.config;
+        consume_redeem_limit(config, amount)?;
+        config.redeem_fee_revenue = config
+            .redeem_fee_revenue
+            .checked_add(fee)
+            .ok_or(StablecoinError::Overflow)?;
+        config.usdt_reserve = config
+            .usdt_reserve
+            .checked_sub(amount_after_fee)
+            .ok_or(StablecoinError::InsufficientReserves)?;
+        config.total_supply = config
+            .total_supply
+            .checked_sub(amount_after_fee)
+            .ok_or(StablecoinError::Overflow)?;
+
+        // Move the fee into the protocol revenue vault
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        if fee > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.holder_token_account.to_account_info(),
+                to: ctx.accounts.revenue_vault.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts);
+            token::transfer(cpi_ctx, fee)?;
+
+            // Emit event
+            emit!(RedeemFeeCollectedEvent {
+                payer: ctx.accounts.holder.key(),
+                fee,
+                total: ctx.accounts.config.redeem_fee_revenue,
+            });
+        }
+
+        // Burn the rest of the holder's Establo
+        let cpi_accounts = token::Burn {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.holder_token_account.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts);
+        token::burn(cpi_ctx, amount_after_fee)?;
+
+        // Pay USDT out of the reserve vault, signed by the vault authority PDA
+        let bump = *ctx.bumps.get("vault_authority").unwrap();
+        let vault_authority_seeds: &[&[u8]] = &[b"vault_authority", &[bump]];
+        let signer = &[vault_authority_seeds];
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.usdt_vault.to_account_info(),
+            to: ctx.accounts.holder_usdt_account.to_account_info(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, usdt_amount)?;
+
+        // Emit event
+        emit!(RedeemEvent {
+            from: ctx.accounts.holder.key(),
+            amount: amount_after_fee,
+            usdt_amount,
+        });
+
+        Ok(())
+    }
+
+    // Withdraw protocol revenue to fund operations
+    // Requires an approved multisig proposal for this destination and amount, executed by a FeeManager
+    pub fn withdraw_revenue(ctx: Context<WithdrawRevenue>, amount: u64) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        let action = PrivilegedAction::WithdrawRevenue {
+            destination: ctx.accounts.destination.key(),
+            amount,
+        };
+        multisig::execute_proposal(&ctx.accounts.admin_set, &mut ctx.accounts.proposal, &action)?;
+
+        // Pay out of the revenue vault, signed by the vault authority PDA
+        let bump = *ctx.bumps.get("vault_authority").unwrap();
+        let vault_authority_seeds: &[&[u8]] = &[b"vault_authority", &[bump]];
+        let signer = &[vault_authority_seeds];
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.revenue_vault.to_account_info(),
+            to: ctx.accounts.destination.to_account_info(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, amount)?;
+
+        let config = &mut ctx.accounts.config;
+        config.revenue_withdrawn = config
+            .revenue_withdrawn
+            .checked_add(amount)
+            .ok_or(StablecoinError::Overflow)?;
+
+        // Emit event
+        emit!(RevenueWithdrawnEvent {
+            destination: ctx.accounts.destination.key(),
+            amount,
+            total_withdrawn: config.revenue_withdrawn,
+        });
+
+        Ok(())
+    }
+
+    // Create the program-owned USDT reserve vault (ReserveManager)
+    pub fn initialize_reserve_vault(_ctx: Context<InitializeReserveVault>) -> Result<()> {
+        Ok(())
+    }
+
+    // Burn tokens
+    // Requires an approved multisig proposal for this token account and amount, executed by a Burner
+    // The holder approves the mint authority PDA as delegate beforehand, so no holder signature is needed here
+    pub fn burn(ctx: Context<BurnTokens>, amount: u64) -> Result<()> {
+        let action = PrivilegedAction::Burn {
+            token_account: ctx.accounts.token_account.key(),
+            amount,
+        };
+        multisig::execute_proposal(&ctx.accounts.admin_set, &mut ctx.accounts.proposal, &action)?;
+
+        let token_account = &ctx.accounts.token_account;
+        require!(
+            token_account.delegate == COption::Some(ctx.accounts.mint_authority.key())
+                && token_account.delegated_amount >= amount,
+            StablecoinError::BurnNotDelegated
+        );
+
+        // Burn tokens from account, signed by the mint authority PDA as delegate
+        let bump = *ctx.bumps.get("mint_authority").unwrap();
+        let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[bump]];
+        let signer = &[mint_authority_seeds];
+        let cpi_accounts = token::Burn {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.token_account.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::burn(cpi_ctx, amount)?;
+
+        // Track outstanding supply
+        let config = &mut ctx.accounts.config;
+        config.total_supply = config
+            .total_supply
+            .checked_sub(amount)
+            .ok_or(StablecoinError::Overflow)?;
+
+        // Emit event
+        emit!(BurnEvent {
+            from: ctx.accounts.token_account.owner,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    // Get reserve status, returned via return data so clients can read it by simulation
+    pub fn get_reserves(ctx: Context<GetReserves>) -> Result<ReserveStatus> {
+        let config = &ctx.accounts.config;
+        let usdt_vault_balance = normalize_amount(
+            ctx.accounts.usdt_vault.amount,
+            ctx.accounts.usdt_mint.decimals,
+            config.decimals,
+        )?;
+        let (target_usdt_bps, target_re_bps) =
+            collateral_targets(config, Clock::get()?.unix_timestamp);
+
+        Ok(ReserveStatus {
+            total_supply: config.total_supply,
+            usdt_reserve: config.usdt_reserve,
+            usdt_vault_balance,
+            real_estate_value: config.real_estate_value,
+            haircut_real_estate_value: config.haircut_real_estate_value,
+            collateral_ratio_bps: collateral_ratio_bps(config),
+            target_ratio_bps: target_usdt_bps as u64 + target_re_bps as u64,
+            target_usdt_bps,
+            target_re_bps,
+            dao_contributions: config.dao_contributions,
+            mint_fee_revenue: config.mint_fee_revenue,
+            redeem_fee_revenue: config.redeem_fee_revenue,
+            revenue_withdrawn: config.revenue_withdrawn,
+            last_sync_slot: config.last_sync_slot,
+        })
+    }
+
+    // Sync reserves from on-chain state: the USDT vault balance and the
+    // aggregate value of properties registered in the RWA marketplace, raw and after haircuts
+    // Only copies balances the program already controls, so a ReserveManager can run it without a proposal
+    pub fn sync_reserves(ctx: Context<SyncReserves>) -> Result<()> {
+        let usdt_reserve = normalize_amount(
+            ctx.accounts.usdt_vault.amount,
+            ctx.accounts.usdt_mint.decimals,
+            ctx.accounts.config.decimals,
+        )?;
+        let real_estate_value = ctx.accounts.marketplace.total_property_value;
+        let haircut_real_estate_value = ctx.accounts.marketplace.haircut_property_value()?;
+
+        let config = &mut ctx.accounts.config;
+        let old_usdt_reserve = config.usdt_reserve;
+        let old_real_estate_value = config.real_estate_value;
+        let old_haircut_real_estate_value = config.haircut_real_estate_value;
+        config.usdt_reserve = usdt_reserve;
+        config.real_estate_value = real_estate_value;
+        config.haircut_real_estate_value = haircut_real_estate_value;
+        config.last_sync_slot = Clock::get()?.slot;
+
+        // Emit event
+        emit!(ReservesUpdatedEvent {
+            old_usdt_reserve,
+            usdt_reserve,
+            old_real_estate_value,
+            real_estate_value,
+            old_haircut_real_estate_value,
+            haircut_real_estate_value,
+            slot: config.last_sync_slot,
+        });
+
+        Ok(())
+    }
+
+    // Append a hash-chained proof-of-reserves snapshot
+    pub fn snapshot_reserves(ctx: Context<proof_of_reserves::SnapshotReserves>) -> Result<()> {
+        proof_of_reserves::snapshot_reserves(ctx)
+    }
+
+    // Peg stability module
+    // Create the USDT PSM with its fees and debt ceiling through an approved proposal (ReserveManager)
+    pub fn initialize_psm(
+        ctx: Context<psm::InitializePsm>,
+        tin_bps: u16,
+        tout_bps: u16,
+        debt_ceiling: u64,
+    ) -> Result<()> {
+        psm::initialize_psm(ctx, tin_bps, tout_bps, debt_ceiling)
+    }
+
+    // Update the PSM fees and debt ceiling through an approved proposal (ReserveManager)
+    pub fn set_psm_params(
+        ctx: Context<psm::SetPsmParams>,
+        tin_bps: u16,
+        tout_bps: u16,
+        debt_ceiling: u64,
+    ) -> Result<()> {
+        psm::set_psm_params(ctx, tin_bps, tout_bps, debt_ceiling)
+    }
+
+    // Swap USDT for Establo at par
+    pub fn swap_in(ctx: Context<psm::SwapIn>, collateral_amount: u64) -> Result<()> {
+        psm::swap_in(ctx, collateral_amount)
+    }
+
+    // Swap Establo for USDT at par
+    pub fn swap_out(ctx: Context<psm::SwapOut>, amount: u64) -> Result<()> {
+        psm::swap_out(ctx, amount)
+    }
+
+    // Auditor-signed attestation of off-chain property valuations
+    // Create the audit registry with its auditor key
+    pub fn initialize_audit_registry(
+        ctx: Context<audit::InitializeAuditRegistry>,
+        auditor: Pubkey,
+    ) -> Result<()> {
+        audit::initialize_audit_registry(ctx, auditor)
+    }
+
+    // Replace the registered auditor key
+    pub fn set_auditor(ctx: Context<audit::SetAuditor>, auditor: Pubkey) -> Result<()> {
+        audit::set_auditor(ctx, auditor)
+    }
+
+    // Post an auditor-signed Merkle root of property valuations
+    pub fn post_audit_root(
+        ctx: Context<audit::PostAuditRoot>,
+        merkle_root: [u8; 32],
+        audit_epoch: u64,
+    ) -> Result<()> {
+        audit::post_audit_root(ctx, merkle_root, audit_epoch)
+    }
+
+    // Prove a property's value is part of the latest audited root
+    pub fn prove_property_value(
+        ctx: Context<audit::ProvePropertyValue>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        audit::prove_property_value(ctx, proof)
+    }
+
+    // Propose a new DAO token account for green ecosystem initiatives
+    // The change only takes effect after the DAO timelock has elapsed
+    pub fn propose_dao_account(ctx: Context<ProposeDaoAccount>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(ctx.accounts.admin.key() == config.admin, StablecoinError::Unauthorized);
+        // A pending change must be executed or cancelled before another is proposed
+        require!(
+            config.pending_dao_account == Pubkey::default(),
+            StablecoinError::DaoUpdatePending
+        );
+
+        let eta = Clock::get()?
+            .unix_timestamp
+            .checked_add(config.dao_timelock)
+            .ok_or(StablecoinError::Overflow)?;
+        config.pending_dao_account = ctx.accounts.new_dao_token_account.key();
+        config.dao_update_eta = eta;
+
+        // Emit event
+        emit!(DaoUpdateProposedEvent {
+            current_dao_account: config.dao_token_account,
+            pending_dao_account: config.pending_dao_account,
+            eta,
+        });
+
+        Ok(())
+    }
+
+    // Execute a proposed DAO account change once its timelock has elapsed
+    pub fn execute_dao_account(ctx: Context<ExecuteDaoAccount>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(ctx.accounts.admin.key() == config.admin, StablecoinError::Unauthorized);
+        require!(
+            config.pending_dao_account != Pubkey::default(),
+            StablecoinError::NoPendingDaoAccount
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= config.dao_update_eta,
+            StablecoinError::DaoTimelockNotElapsed
+        );
+
+        let old_dao_account = config.dao_token_account;
+        config.dao_token_account = config.pending_dao_account;
+        config.pending_dao_account = Pubkey::default();
+        config.dao_update_eta = 0;
+
+        // Emit event
+        emit!(DaoUpdatedEvent {
+            old_dao_account,
+            new_dao_account: config.dao_token_account,
+        });
+
+        Ok(())
+    }
+
+    // Cancel a pending DAO account change
+    pub fn cancel_dao_account(ctx: Context<UpdateDao>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(ctx.accounts.admin.key() == config.admin, StablecoinError::Unauthorized);
+        require!(
+            config.pending_dao_account != Pubkey::default(),
+            StablecoinError::NoPendingDaoAccount
+        );
+
+        let cancelled_dao_account = config.pending_dao_account;
+        config.pending_dao_account = Pubkey::default();
+        config.dao_update_eta = 0;
+
+        // Emit event
+        emit!(DaoUpdateCancelledEvent { cancelled_dao_account });
+
+        Ok(())
+    }
+
+    // Change the delay applied to DAO account changes
+    pub fn set_dao_timelock(ctx: Context<UpdateDao>, dao_timelock: i64) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(ctx.accounts.admin.key() == config.admin, StablecoinError::Unauthorized);
+        require!(dao_timelock >= MIN_DAO_TIMELOCK, StablecoinError::InvalidTimelock);
+
+        let old_dao_timelock = config.dao_timelock;
+        config.dao_timelock = dao_timelock;
+
+        // Emit event
+        emit!(DaoTimelockUpdatedEvent {
+            old_dao_timelock,
+            dao_timelock,
+        });
+
+        Ok(())
+    }
+
+    // Pause or unpause stablecoin operations (Pauser)
+    // `paused` replaces the current mask; a zero mask resumes everything
+    pub fn set_paused(ctx: Context<SetPaused>, paused: u8) -> Result<()> {
+        require!(
+            paused & !STABLECOIN_PAUSE_FLAGS == 0,
+            StablecoinError::InvalidPauseFlags
+        );
+
+        let config = &mut ctx.accounts.config;
+        let old_paused = config.paused;
+        config.paused = paused;
+
+        // Emit event
+        emit!(PauseUpdatedEvent {
+            old_paused,
+            paused,
+            pauser: ctx.accounts.pauser.key(),
+        });
+
+        Ok(())
+    }
+
+    // Move the collateral targets, ramped linearly from their current values over `ramp_duration`
+    // Requires an approved multisig proposal for these targets, executed by a RiskManager
+    pub fn set_collateral_targets(
+        ctx: Context<SetCollateral>,
+        target_usdt_bps: u16,
+        target_re_bps: u16,
+        ramp_duration: i64,
+    ) -> Result<()> {
+        let action = PrivilegedAction::SetCollateralTargets {
+            target_usdt_bps,
+            target_re_bps,
+            ramp_duration,
+        };
+        multisig::execute_proposal(&ctx.accounts.admin_set, &mut ctx.accounts.proposal, &action)?;
+
+        let config = &mut ctx.accounts.config;
+        require!(
+            ramp_duration >= MIN_COLLATERAL_RAMP_DURATION,
+            StablecoinError::InvalidRampDuration
+        );
+        require!(
+            collateral_targets_valid(config, target_usdt_bps, target_re_bps),
+            StablecoinError::InvalidCollateralTargets
+        );
+
+        // A new ramp starts from wherever the current one has reached
+        let now = Clock::get()?.unix_timestamp;
+        let (old_usdt_bps, old_re_bps) = collateral_targets(config, now);
+        config.ramp_start_usdt_bps = old_usdt_bps;
+        config.ramp_start_re_bps = old_re_bps;
+        config.ramp_start_time = now;
+        config.ramp_end_time = now
+            .checked_add(ramp_duration)
+            .ok_or(StablecoinError::Overflow)?;
+        config.target_usdt_bps = target_usdt_bps;
+        config.target_re_bps = target_re_bps;
+
+        // Emit event
+        emit!(CollateralTargetsUpdatedEvent {
+            old_usdt_bps,
+            old_re_bps,
+            target_usdt_bps,
+            target_re_bps,
+            ramp_end_time: config.ramp_end_time,
+        });
+
+        Ok(())
+    }
+
+    // Set the bounds collateral targets must stay within
+    // Requires an approved multisig proposal for these bounds, executed by a RiskManager
+    pub fn set_collateral_bounds(
+        ctx: Context<SetCollateral>,
+        min_usdt_bps: u16,
+        max_usdt_bps: u16,
+        min_re_bps: u16,
+        max_re_bps: u16,
+    ) -> Result<()> {
+        let action = PrivilegedAction::SetCollateralBounds {
+            min_usdt_bps,
+            max_usdt_bps,
+            min_re_bps,
+            max_re_bps,
+        };
+        multisig::execute_proposal(&ctx.accounts.admin_set, &mut ctx.accounts.proposal, &action)?;
+
+        let config = &mut ctx.accounts.config;
+        require!(
+            min_usdt_bps <= max_usdt_bps
+                && max_usdt_bps <= 10_000
+                && min_re_bps <= max_re_bps
+                && max_re_bps <= 10_000,
+            StablecoinError::InvalidCollateralBounds
+        );
+
+        config.min_usdt_bps = min_usdt_bps;
+        config.max_usdt_bps = max_usdt_bps;
+        config.min_re_bps = min_re_bps;
+        config.max_re_bps = max_re_bps;
+
+        // The running target must still fit the new bounds
+        require!(
+            collateral_targets_valid(config, config.target_usdt_bps, config.target_re_bps),
+            StablecoinError::InvalidCollateralBounds
+        );
+
+        // Emit event
+        emit!(CollateralBoundsUpdatedEvent {
+            min_usdt_bps,
+            max_usdt_bps,
+            min_re_bps,
+            max_re_bps,
+        });
+
+        Ok(())
+    }
+
+    // Set the USDT price feed and the depeg guard limits
+    pub fn set_usdt_oracle(
+        ctx: Context<SetUsdtOracle>,
+        min_usdt_price_bps: u16,
+        max_oracle_staleness: i64,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(ctx.accounts.admin.key() == config.admin, StablecoinError::Unauthorized);
+        require!(
+            min_usdt_price_bps <= 10_000 && max_oracle_staleness > 0,
+            StablecoinError::InvalidOracleConfig
+        );
+
+        // Reject accounts that aren't readable price feeds up front
+        oracle::load_price_account(&ctx.accounts.usdt_oracle)?;
+
+        config.usdt_oracle = ctx.accounts.usdt_oracle.key();
+        config.min_usdt_price_bps = min_usdt_price_bps;
+        config.max_oracle_staleness = max_oracle_staleness;
+
+        // Emit event
+        emit!(OracleUpdatedEvent {
+            usdt_oracle: config.usdt_oracle,
+            min_usdt_price_bps,
+            max_oracle_staleness,
+        });
+
+        Ok(())
+    }
+
+    // Configure the per-window mint and redemption caps; a zero cap disables that limit
+    pub fn set_rate_limits(
+        ctx: Context<SetRateLimits>,
+        rate_limit_window: i64,
+        mint_limit: u64,
+        redeem_limit: u64,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(ctx.accounts.admin.key() == config.admin, StablecoinError::Unauthorized);
+        require!(rate_limit_window > 0, StablecoinError::InvalidRateLimit);
+
+        config.rate_limit_window = rate_limit_window;
+        config.mint_limit = mint_limit;
+        config.redeem_limit = redeem_limit;
+
+        // Emit event
+        emit!(RateLimitsUpdatedEvent {
+            rate_limit_window,
+            mint_limit,
+            redeem_limit,
+        });
+
+        Ok(())
+    }
+
+    // Propose a new admin, who must accept before taking over
+    // Proposing the default pubkey cancels a pending handover
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(ctx.accounts.admin.key() == config.admin, StablecoinError::Unauthorized);
+
+        config.pending_admin = new_admin;
+
+        // Emit event
+        emit!(AdminProposedEvent {
+            current_admin: config.admin,
+            pending_admin: new_admin,
+        });
+
+        Ok(())
+    }
+
+    // Accept a pending admin handover
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let old_admin = config.admin;
+        config.admin = ctx.accounts.pending_admin.key();
+        config.pending_admin = Pubkey::default();
+
+        // Emit event
+        emit!(AdminUpdatedEvent {
+            old_admin,
+            new_admin: config.admin,
+        });
+
+        Ok(())
+    }
+
+    // PHASE 2 FEATURES - TO BE IMPLEMENTED
+    // The following features will be implemented in the next phase:
+
+    // Get DAO contributions to green ecosystem initiatives
+    /*
+    pub fn get_dao_contributions(ctx: Context<GetDaoContributions>) -> Result<u64> {
+        Ok(ctx.accounts.config.dao_contributions)
+    }
+    */
+
+    // RWA Marketplace Core Functions - Minimal Implementation for MVP
+    // Initialize the RWA marketplace
+    pub fn initialize_marketplace(
+        ctx: Context<rwa_marketplace::Initialize>,
+        stablecoin_config_address: Pubkey
+    ) -> Result<()> {
+        rwa_marketplace::initialize(ctx, stablecoin_config_address)
+    }
+
+    // Upgrade a marketplace created by the first release to the current layout
+    pub fn migrate_marketplace(ctx: Context<rwa_marketplace::MigrateMarketplace>) -> Result<()> {
+        rwa_marketplace::migrate_marketplace(ctx)
+    }
+
+    // Upgrade a property listed by the first release, assigning its class
+    pub fn migrate_property(
+        ctx: Context<rwa_marketplace::MigrateProperty>,
+        property_class: rwa_marketplace::PropertyClass,
+    ) -> Result<()> {
+        rwa_marketplace::migrate_property(ctx, property_class)
+    }
+
+    // List a new Real World Asset
+    pub fn list_rwa(
+        ctx: Context<rwa_marketplace::ListRWA>,
+        uri: String,
+        name: String,
+        symbol: String,
+        asset_value: u64,
+        location: String,
+        property_details: String,
+        liquidation_threshold: Option<u8>,
+        property_class: rwa_marketplace::PropertyClass,
+    ) -> Result<()> {
+        rwa_marketplace::list_rwa(ctx, uri, name, symbol, asset_value, location, property_details, liquidation_threshold, property_class)
+    }
+
+    // Update an RWA valuation (Appraiser)
+    pub fn update_valuation(
+        ctx: Context<rwa_marketplace::UpdateValuation>,
+        new_value: u64
+    ) -> Result<()> {
+        rwa_marketplace::update_valuation(ctx, new_value)
+    }
+
+    // Liquidate an at-risk RWA through an approved multisig proposal (Liquidator)
+    pub fn liquidate_rwa(
+        ctx: Context<rwa_marketplace::LiquidateRWA>
+    ) -> Result<()> {
+        rwa_marketplace::liquidate_rwa(ctx)
+    }
+
+    // Pause or unpause marketplace operations (Pauser)
+    pub fn set_marketplace_paused(
+        ctx: Context<rwa_marketplace::SetPaused>,
+        paused: u8
+    ) -> Result<()> {
+        rwa_marketplace::set_paused(ctx, paused)
+    }
+
+    // Set the default liquidation threshold for new RWAs (Liquidator)
+    pub fn set_liquidation_threshold(
+        ctx: Context<rwa_marketplace::SetLiquidationThreshold>,
+        threshold: u8
+    ) -> Result<()> {
+        rwa_marketplace::set_liquidation_threshold(ctx, threshold)
+    }
+
+    // Set the loan-to-value haircut for a property class (RiskManager)
+    pub fn set_haircut(
+        ctx: Context<rwa_marketplace::SetHaircut>,
+        property_class: rwa_marketplace::PropertyClass,
+        haircut_bps: u16
+    ) -> Result<()> {
+        rwa_marketplace::set_haircut(ctx, property_class, haircut_bps)
+    }
+
+    // Multisig authority for privileged instructions
+    // Create the M-of-N admin set
+    pub fn initialize_admin_set(
+        ctx: Context<multisig::InitializeAdminSet>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        multisig::initialize_admin_set(ctx, signers, threshold)
+    }
+
+    // Replace the admin set through an approved proposal
+    pub fn update_admin_set(ctx: Context<multisig::UpdateAdminSet>) -> Result<()> {
+        multisig::update_admin_set(ctx)
+    }
+
+    // Propose a privileged action
+    pub fn propose_action(
+        ctx: Context<multisig::ProposeAction>,
+        action: PrivilegedAction,
+    ) -> Result<()> {
+        multisig::propose_action(ctx, action)
+    }
+
+    // Approve a proposed privileged action
+    pub fn approve_action(ctx: Context<multisig::ApproveAction>) -> Result<()> {
+        multisig::approve_action(ctx)
+    }
+
+    // Cancel a pending proposal
+    pub fn cancel_proposal(ctx: Context<multisig::CancelProposal>) -> Result<()> {
+        multisig::cancel_proposal(ctx)
+    }
+
+    // Role registry for least-privilege operational keys
+    // Grant a role to a wallet
+    pub fn grant_role(ctx: Context<roles::GrantRole>, role: Role, wallet: Pubkey) -> Result<()> {
+        roles::grant_role(ctx, role, wallet)
+    }
+
+    // Revoke a role from a wallet
+    pub fn revoke_role(ctx: Context<roles::RevokeRole>) -> Result<()> {
+        roles::revoke_role(ctx)
+    }
+
+    // Propose a new marketplace admin
+    pub fn propose_marketplace_admin(
+        ctx: Context<rwa_marketplace::ProposeAdmin>,
+        new_admin: Pubkey
+    ) -> Result<()> {
+        rwa_marketplace::propose_admin(ctx, new_admin)
+    }
+
+    // Accept a pending marketplace admin handover
+    pub fn accept_marketplace_admin(
+        ctx: Context<rwa_marketplace::AcceptAdmin>
+    ) -> Result<()> {
+        rwa_marketplace::accept_admin(ctx)
+    }
+
+    // PHASE 2 FEATURES - TO BE IMPLEMENTED
+    // The following RWA marketplace features will be implemented in the next phase:
+
+    /*
+    // Transfer RWA ownership
+    pub fn transfer_rwa(
+        ctx: Context<rwa_marketplace::TransferRWA>
+    ) -> Result<()> {
+        rwa_marketplace::transfer_rwa(ctx)
+    }
+    */
+}
+
+// Helper function to check whether a per-wallet PDA (fee exemption, blacklist) has been created
+pub(crate) fn pda_exists(account: &AccountInfo) -> bool {
+    account.owner == &crate::ID && !account.data_is_empty()
+}
+
+// Helper function to calculate the Green Ecosystem DAO fee for a transfer
+pub fn transfer_fee(config: &Config, amount: u64) -> u64 {
+    // The largest bracket the amount reaches sets the rate, otherwise the base rate applies
+    let fee_bps = config
+        .fee_tiers
+        .iter()
+        .rev()
+        .find(|tier| amount >= tier.min_amount)
+        .map(|tier| tier.fee_bps)
+        .unwrap_or(config.fee_bps);
+
+    // The rate is at most 100%, so the narrowing cast is safe; the minimum fee is
+    // capped at the amount so small transfers are never charged more than they move
+    let fee = (amount as u128 * fee_bps as u128 / 10_000) as u64;
+    fee.max(config.min_fee).min(amount)
+}
+
+// Helper function to compute a flat protocol fee; never exceeds the amount itself
+pub(crate) fn protocol_fee(amount: u64, fee_bps: u16) -> u64 {
+    (amount as u128 * fee_bps.min(10_000) as u128 / 10_000) as u64
+}
+
+// Helper function to check that a KYC attestation is still valid
+pub(crate) fn check_attestation(attestation: &Attestation) -> Result<()> {
+    require!(
+        attestation.expiry > Clock::get()?.unix_timestamp,
+        StablecoinError::AttestationExpired
+    );
+    require!(
+        attestation.level >= MIN_ATTESTATION_LEVEL,
+        StablecoinError::InsufficientAttestationLevel
+    );
+    Ok(())
+}
+
+// Helper function to start a new rate limit window once the current one has elapsed
+fn roll_rate_limit_window(config: &mut Config) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    if now >= config.window_start.saturating_add(config.rate_limit_window) {
+        emit!(RateLimitWindowRolledEvent {
+            previous_window_start: config.window_start,
+            minted: config.minted_in_window,
+            redeemed: config.redeemed_in_window,
+            window_start: now,
+        });
+        config.window_start = now;
+        config.minted_in_window = 0;
+        config.redeemed_in_window = 0;
+    }
+    Ok(())
+}
+
+// Helper function to count a mint against the current window's cap
+pub(crate) fn consume_mint_limit(config: &mut Config, amount: u64) -> Result<()> {
+    roll_rate_limit_window(config)?;
+    config.minted_in_window = config
+        .minted_in_window
+        .checked_add(amount)
+        .ok_or(StablecoinError::Overflow)?;
+    require!(
+        config.mint_limit == 0 || config.minted_in_window <= config.mint_limit,
+        StablecoinError::RateLimitExceeded
+    );
+    Ok(())
+}
+
+// Helper function to count a redemption against the current window's cap
+pub(crate) fn consume_redeem_limit(config: &mut Config, amount: u64) -> Result<()> {
+    roll_rate_limit_window(config)?;
+    config.redeemed_in_window = config
+        .redeemed_in_window
+        .checked_add(amount)
+        .ok_or(StablecoinError::Overflow)?;
+    require!(
+        config.redeem_limit == 0 || config.redeemed_in_window <= config.redeem_limit,
+        StablecoinError::RateLimitExceeded
+    );
+    Ok(())
+}
+
+// Helper function to build a config with the protocol defaults, shared by `initialize` and `migrate_config`
+fn default_config(
+    admin: Pubkey,
+    usdt_mint: Pubkey,
+    dao_token_account: Pubkey,
+    mint: Pubkey,
+    decimals: u8,
+    total_supply: u64,
+    usdt_oracle: Pubkey,
+) -> Config {
+    Config {
+        admin,
+        usdt_mint,
+        dao_token_account,  // Green Ecosystem DAO address for SDG initiatives
+        mint,
+        decimals,
+        total_supply,
+        fee_bps: DEFAULT_FEE_BPS,
+        dao_timelock: DEFAULT_DAO_TIMELOCK,
+        rate_limit_window: DEFAULT_RATE_LIMIT_WINDOW,
+        usdt_oracle,
+        min_usdt_price_bps: DEFAULT_MIN_USDT_PRICE_BPS,
+        max_oracle_staleness: DEFAULT_MAX_ORACLE_STALENESS,
+        target_usdt_bps: DEFAULT_TARGET_USDT_BPS,
+        target_re_bps: DEFAULT_TARGET_RE_BPS,
+        ramp_start_usdt_bps: DEFAULT_TARGET_USDT_BPS,
+        ramp_start_re_bps: DEFAULT_TARGET_RE_BPS,
+        min_usdt_bps: DEFAULT_MIN_USDT_BPS,
+        max_usdt_bps: DEFAULT_MAX_USDT_BPS,
+        min_re_bps: DEFAULT_MIN_RE_BPS,
+        max_re_bps: DEFAULT_MAX_RE_BPS,
+        // Contributions, reserves, limits and mint/redeem fees all start at zero
+        ..Config::default()
+    }
+}
+
+// Helper function to read an account still in the layout of the first release
+// Only the legacy prefix is decoded; accounts already at their current size are rejected
+pub(crate) fn read_legacy_account<T: AnchorDeserialize>(
+    account: &AccountInfo,
+    discriminator: [u8; 8],
+    legacy_space: usize,
+) -> Result<T> {
+    require_keys_eq!(*account.owner, crate::ID, StablecoinError::InvalidLegacyAccount);
+    let data = account.try_borrow_data()?;
+    require!(data.len() <= legacy_space, StablecoinError::AlreadyMigrated);
+    require!(
+        data.len() == legacy_space && data[..8] == discriminator,
+        StablecoinError::InvalidLegacyAccount
+    );
+    T::deserialize(&mut &data[8..]).map_err(|_| error!(StablecoinError::InvalidLegacyAccount))
+}
+
+// Helper function to grow an account to `space` and rewrite it in the current layout
+// `payer` tops up the rent exemption for the added bytes
+pub(crate) fn write_migrated_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    value: &T,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let top_up = rent.saturating_sub(account.lamports());
+    if top_up > 0 {
+        let cpi_accounts = anchor_lang::system_program::Transfer {
+            from: payer.clone(),
+            to: account.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.clone(), cpi_accounts);
+        anchor_lang::system_program::transfer(cpi_ctx, top_up)?;
+    }
+    account.realloc(space, true)?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    value.try_serialize(&mut writer)
+}
+
+// Helper function to check if the outstanding supply plus a new mint is backed
+// The USDT reserve is valued at the oracle price, never above $1
+// The USDT target is a floor; real estate counts up to its target and USDT may cover the rest
+pub(crate) fn is_backed(config: &Config, usdt_price_bps: u64, additional_amount: u64, now: i64) -> bool {
+    let supply = match config.total_supply.checked_add(additional_amount) {
+        Some(supply) => supply as u128,
+        None => return false,
+    };
+    let usdt_value = config.usdt_reserve as u128 * usdt_price_bps.min(10_000) as u128 / 10_000;
+
+    // Calculate required backing amounts from the current collateral targets
+    let (target_usdt_bps, target_re_bps) = collateral_targets(config, now);
+    let required_usdt = supply * target_usdt_bps as u128 / 10_000;
+    let required_real_estate = supply * target_re_bps as u128 / 10_000;
+
+    // Verify that reserves meet requirements
+    let real_estate_value = (config.haircut_real_estate_value as u128).min(required_real_estate);
+    usdt_value >= required_usdt
+        && usdt_value + real_estate_value >= required_usdt + required_real_estate
+}
+
+// Helper function to compute the (USDT, real estate) backing targets at `now`
+// Targets move linearly from the ramp start values to the new targets
+fn collateral_targets(config: &Config, now: i64) -> (u16, u16) {
+    if now >= config.ramp_end_time || config.ramp_end_time <= config.ramp_start_time {
+        return (config.target_usdt_bps, config.target_re_bps);
+    }
+    if now <= config.ramp_start_time {
+        return (config.ramp_start_usdt_bps, config.ramp_start_re_bps);
+    }
+
+    let elapsed = (now - config.ramp_start_time) as i128;
+    let duration = (config.ramp_end_time - config.ramp_start_time) as i128;
+    let interpolate = |from: u16, to: u16| {
+        let from = from as i128;
+        (from + (to as i128 - from) * elapsed / duration) as u16
+    };
+    (
+        interpolate(config.ramp_start_usdt_bps, config.target_usdt_bps),
+        interpolate(config.ramp_start_re_bps, config.target_re_bps),
+    )
+}
+
+// Helper function to check collateral targets against the bounds
+// Together the legs must at least fully back the supply
+fn collateral_targets_valid(config: &Config, target_usdt_bps: u16, target_re_bps: u16) -> bool {
+    target_usdt_bps >= config.min_usdt_bps
+        && target_usdt_bps <= config.max_usdt_bps
+        && target_re_bps >= config.min_re_bps
+        && target_re_bps <= config.max_re_bps
+        && target_usdt_bps as u32 + target_re_bps as u32 >= 10_000
+}
+
+// Helper function to compute total reserves over outstanding supply, in basis points
+fn collateral_ratio_bps(config: &Config) -> u64 {
+    if config.total_supply == 0 {
+        return u64::MAX;
+    }
+    let reserves = config.usdt_reserve as u128 + config.haircut_real_estate_value as u128;
+    let ratio = reserves * 10_000 / config.total_supply as u128;
+    u64::try_from(ratio).unwrap_or(u64::MAX)
+}
+
+// Convert an amount between two token decimal precisions
+pub(crate) fn normalize_amount(amount: u64, from_decimals: u8, to_decimals: u8) -> Result<u64> {
+    let normalized = if to_decimals >= from_decimals {
+        10u64
+            .checked_pow((to_decimals - from_decimals) as u32)
+            .and_then(|factor| amount.checked_mul(factor))
+    } else {
+        10u64
+            .checked_pow((from_decimals - to_decimals) as u32)
+            .map(|factor| amount / factor)
+    };
+    normalized.ok_or_else(|| error!(StablecoinError::Overflow))
+}
+
+// Account structs
+#[account]
+#[derive(Default)]
+pub struct Config {
+    pub admin: Pubkey,              // Admin authority
+    pub usdt_mint: Pubkey,          // USDT mint address
+    pub dao_token_account: Pubkey,  // Green Ecosystem DAO token account for SDG initiatives
+    pub mint: Pubkey,               // Stablecoin mint
+    pub decimals: u8,               // Token decimals
+    pub dao_contributions: u64,     // Total contributions to green ecosystem initiatives (SDGs)
+    pub usdt_reserve: u64,          // USDT reserve (70%), in stablecoin base units
+    pub real_estate_value: u64,     // Real estate value (30%)
+    pub total_supply: u64,          // Outstanding Establo supply
+    pub fee_bps: u16,               // Base Green Ecosystem DAO fee (basis points)
+    pub min_fee: u64,               // Minimum fee charged per transfer
+    pub fee_tiers: Vec<FeeTier>,    // Fee brackets by transfer size, sorted ascending
+    pub last_sync_slot: u64,        // Slot of the last reserve sync from on-chain balances
+    pub pending_dao_account: Pubkey, // Proposed DAO token account, default when none is pending
+    pub dao_update_eta: i64,        // Earliest time the pending DAO account can be executed
+    pub dao_timelock: i64,          // Delay applied to DAO account changes (seconds)
+    pub pending_admin: Pubkey,      // Proposed admin, default when none is pending
+    pub paused: u8,                 // Bitmask of paused operations (PAUSE_* flags)
+    pub rate_limit_window: i64,     // Length of the rate limit window (seconds)
+    pub mint_limit: u64,            // Max Establo minted per window, zero when unlimited
+    pub redeem_limit: u64,          // Max Establo redeemed per window, zero when unlimited
+    pub window_start: i64,          // Start of the current rate limit window
+    pub minted_in_window: u64,      // Establo minted in the current window
+    pub redeemed_in_window: u64,    // Establo redeemed in the current window
+    pub snapshot_count: u64,        // Number of reserve snapshots, used as the next index
+    pub last_snapshot_hash: [u8; 32], // Hash of the latest reserve snapshot
+    pub usdt_oracle: Pubkey,        // Pyth-style USDT/USD price account
+    pub min_usdt_price_bps: u16,    // Minting stops below this USDT price (basis points of $1)
+    pub max_oracle_staleness: i64,  // Maximum age of the USDT price (seconds)
+    pub target_usdt_bps: u16,       // USDT backing target once the current ramp ends
+    pub target_re_bps: u16,         // Real estate backing target once the current ramp ends
+    pub ramp_start_usdt_bps: u16,   // USDT target when the current ramp started
+    pub ramp_start_re_bps: u16,     // Real estate target when the current ramp started
+    pub ramp_start_time: i64,
+    pub ramp_end_time: i64,
+    pub min_usdt_bps: u16,          // Bounds on the USDT backing target
+    pub max_usdt_bps: u16,
+    pub min_re_bps: u16,            // Bounds on the real estate backing target
+    pub max_re_bps: u16,
+    pub haircut_real_estate_value: u64, // Real estate value after class haircuts, counts towards backing
+    pub mint_fee_bps: u16,          // Protocol fee on deposit_and_mint (basis points)
+    pub redeem_fee_bps: u16,        // Protocol fee on redeem (basis points)
+    pub mint_fee_revenue: u64,      // Total mint fees collected into the revenue vault
+    pub redeem_fee_revenue: u64,    // Total redeem fees collected into the revenue vault
+    pub revenue_withdrawn: u64,     // Total withdrawn from the revenue vault
+}
+
+// Config layout of the first release, a prefix of `Config`
+#[derive(AnchorDeserialize)]
+struct LegacyConfig {
+    admin: Pubkey,
+    usdt_mint: Pubkey,
+    dao_token_account: Pubkey,
+    mint: Pubkey,
+    decimals: u8,
+    dao_contributions: u64,
+    usdt_reserve: u64,
+    real_estate_value: u64,
+}
+
+// Weighted split of the Green Ecosystem DAO fee across SDG programs
+#[account]
+pub struct FeeSplit {
+    pub beneficiaries: Vec<FeeBeneficiary>, // Empty means the whole fee goes to the DAO account
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct FeeBeneficiary {
+    pub token_account: Pubkey,      // SDG program token account (reforestation, clean energy, water...)
+    pub weight_bps: u16,            // Share of the fee (weights sum to 10,000)
+    pub contributions: u64,         // Total fees received by this beneficiary
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct FeeBeneficiaryWeight {
+    pub token_account: Pubkey,
+    pub weight_bps: u16,
+}
+
+// Marks a wallet as exempt from the Green Ecosystem DAO transfer fee
+#[account]
+pub struct FeeExemption {
+    pub wallet: Pubkey,             // Exempt wallet (treasury, vault authority, marketplace, DAO)
+}
+
+// Marks a wallet as blacklisted from Establo transfers, mints, redemptions and PSM swaps
+#[account]
+pub struct Blacklist {
+    pub wallet: Pubkey,             // Sanctioned or stolen-fund wallet
+}
+
+// Snapshot of the reserves backing Establo, returned by `get_reserves`
+// Amounts are in stablecoin base units
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub struct ReserveStatus {
+    pub total_supply: u64,              // Outstanding Establo supply
+    pub usdt_reserve: u64,              // USDT reserve as last accounted
+    pub usdt_vault_balance: u64,        // Live USDT reserve vault balance
+    pub real_estate_value: u64,         // Aggregate real estate value
+    pub haircut_real_estate_value: u64, // Real estate value counted towards backing
+    pub collateral_ratio_bps: u64,      // Reserves after haircuts over supply, u64::MAX when supply is zero
+    pub target_ratio_bps: u64,          // Collateral ratio the protocol targets (both legs)
+    pub target_usdt_bps: u16,           // Current USDT backing target, mid-ramp if one is running
+    pub target_re_bps: u16,             // Current real estate backing target
+    pub dao_contributions: u64,         // Total Green Ecosystem DAO fees collected
+    pub mint_fee_revenue: u64,          // Total protocol mint fees collected
+    pub redeem_fee_revenue: u64,        // Total protocol redeem fees collected
+    pub revenue_withdrawn: u64,         // Total protocol revenue withdrawn
+    pub last_sync_slot: u64,            // Slot of the last reserve sync
+}
+
+// KYC attestation allowing a wallet to mint and redeem Establo against USDT
+#[account]
+pub struct Attestation {
+    pub wallet: Pubkey,             // Onboarded wallet
+    pub level: u8,                  // KYC level
+    pub expiry: i64,                // Attestation is invalid from this timestamp on
+    pub jurisdiction: [u8; 2],      // ISO 3166-1 alpha-2 country code
+    pub issuer: Pubkey,             // Compliance officer that issued the attestation
+}
+
+// Fee bracket applied to transfers of at least `min_amount`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct FeeTier {
+    pub min_amount: u64,
+    pub fee_bps: u16,
+}
 
 // Context structs
 #[derive(Accounts)]
-pub struct Initialize<'info> {
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = CONFIG_SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = FEE_SPLIT_SPACE,
+        seeds = [b"fee_split"],
+        bump
+    )]
+    pub fee_split: Account<'info, FeeSplit>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(mut, constraint = mint.mint_authority == COption::Some(admin.key()) @ StablecoinError::Unauthorized)]
+    pub mint: Account<'info, Mint>,
+    /// CHECK: This is the PDA for mint authority
+    #[account(seeds = [b"mint_authority"], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+    /// CHECK: This is the PDA for freeze authority
+    #[account(seeds = [b"freeze_authority"], bump)]
+    pub freeze_authority: UncheckedAccount<'info>,
+    /// CHECK: Parsed as a Pyth-style price account
+    pub usdt_oracle: UncheckedAccount<'info>,
+    // Collects the mint and redeem fees, created with the config so fee-taking paths always have it
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"revenue_vault"],
+        bump,
+        token::mint = mint,
+        token::authority = vault_authority,
+    )]
+    pub revenue_vault: Account<'info, TokenAccount>,
+    /// CHECK: This is the PDA that owns the reserve vaults
+    #[account(seeds = [b"vault_authority"], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: Legacy config, decoded and rewritten by the handler since it doesn't deserialize as `Config` yet
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
+    // The first release had no fee split
+    #[account(
+        init,
+        payer = admin,
+        space = FEE_SPLIT_SPACE,
+        seeds = [b"fee_split"],
+        bump
+    )]
+    pub fee_split: Account<'info, FeeSplit>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub mint: Account<'info, Mint>,
+    /// CHECK: Parsed as a Pyth-style price account
+    pub usdt_oracle: UncheckedAccount<'info>,
+    // Collects the mint and redeem fees, which the first release didn't have
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"revenue_vault"],
+        bump,
+        token::mint = mint,
+        token::authority = vault_authority,
+    )]
+    pub revenue_vault: Account<'info, TokenAccount>,
+    /// CHECK: This is the PDA that owns the reserve vaults
+    #[account(seeds = [b"vault_authority"], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateMintAuthority<'info> {
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        address = config.mint @ StablecoinError::InvalidMint,
+        constraint = mint.mint_authority == COption::Some(current_authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub mint: Account<'info, Mint>,
+    pub current_authority: Signer<'info>,
+    /// CHECK: This is the PDA for mint authority
+    #[account(seeds = [b"mint_authority"], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateFreezeAuthority<'info> {
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        address = config.mint @ StablecoinError::InvalidMint,
+        constraint = mint.freeze_authority == COption::Some(current_authority.key()) @ StablecoinError::Unauthorized
+    )]
+    pub mint: Account<'info, Mint>,
+    pub current_authority: Signer<'info>,
+    /// CHECK: This is the PDA for freeze authority
+    #[account(seeds = [b"freeze_authority"], bump)]
+    pub freeze_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeTokenAccount<'info> {
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    pub compliance: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::Compliance as u8], compliance.key().as_ref()], bump)]
+    pub compliance_role: Account<'info, roles::RoleAssignment>,
+    #[account(address = config.mint @ StablecoinError::InvalidMint)]
+    pub mint: Account<'info, Mint>,
+    #[account(mut, constraint = token_account.mint == config.mint @ StablecoinError::InvalidMint)]
+    pub token_account: Account<'info, TokenAccount>,
+    /// CHECK: This is the PDA for freeze authority
+    #[account(seeds = [b"freeze_authority"], bump)]
+    pub freeze_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddToBlacklist<'info> {
+    #[account(mut)]
+    pub compliance: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::Compliance as u8], compliance.key().as_ref()], bump)]
+    pub compliance_role: Account<'info, roles::RoleAssignment>,
     #[account(
         init,
-        payer = admin,
-        space = 8 + 32 + 32 + 32 + 32 + 1 + 8 + 8 + 8,
-        seeds = [b"config"],
+        payer = compliance,
+        space = 8 + 32,
+        seeds = [b"blacklist", wallet.as_ref()],
         bump
     )]
-    pub config: Account<'info, Config>,
+    pub blacklist: Account<'info, Blacklist>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveFromBlacklist<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub compliance: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::Compliance as u8], compliance.key().as_ref()], bump)]
+    pub compliance_role: Account<'info, roles::RoleAssignment>,
+    #[account(
+        mut,
+        close = compliance,
+        seeds = [b"blacklist", blacklist.wallet.as_ref()],
+        bump
+    )]
+    pub blacklist: Account<'info, Blacklist>,
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct IssueAttestation<'info> {
     #[account(mut)]
-    pub mint: Account<'info, Mint>,
+    pub compliance: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::Compliance as u8], compliance.key().as_ref()], bump)]
+    pub compliance_role: Account<'info, roles::RoleAssignment>,
+    #[account(
+        init,
+        payer = compliance,
+        space = 8 + 32 + 1 + 8 + 2 + 32,
+        seeds = [b"attestation", wallet.as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, Attestation>,
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-pub struct StablecoinTransfer<'info> {
+pub struct RevokeAttestation<'info> {
     #[account(mut)]
+    pub compliance: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::Compliance as u8], compliance.key().as_ref()], bump)]
+    pub compliance_role: Account<'info, roles::RoleAssignment>,
+    #[account(
+        mut,
+        close = compliance,
+        seeds = [b"attestation", attestation.wallet.as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+}
+
+#[derive(Accounts)]
+pub struct StablecoinTransfer<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
     pub config: Account<'info, Config>,
     #[account(mut)]
     pub sender: Signer<'info>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = sender_token_account.mint == config.mint @ StablecoinError::InvalidMint,
+        constraint = sender_token_account.owner == sender.key() @ StablecoinError::Unauthorized
+    )]
     pub sender_token_account: Account<'info, TokenAccount>,
-    #[account(mut)]
+    #[account(mut, constraint = recipient_token_account.mint == config.mint @ StablecoinError::InvalidMint)]
     pub recipient_token_account: Account<'info, TokenAccount>,
-    #[account(mut, constraint = recipient_token_account.mint == config.mint)]
+    #[account(mut, address = config.dao_token_account @ StablecoinError::InvalidDaoAccount)]
     pub dao_token_account: Account<'info, TokenAccount>,
+    #[account(mut, seeds = [b"fee_split"], bump)]
+    pub fee_split: Account<'info, FeeSplit>,
+    /// CHECK: Sender's fee exemption PDA, only checked for existence
+    #[account(seeds = [b"fee_exemption", sender.key().as_ref()], bump)]
+    pub sender_fee_exemption: UncheckedAccount<'info>,
+    /// CHECK: Recipient's fee exemption PDA, only checked for existence
+    #[account(seeds = [b"fee_exemption", recipient_token_account.owner.as_ref()], bump)]
+    pub recipient_fee_exemption: UncheckedAccount<'info>,
+    /// CHECK: Sender's blacklist PDA, only checked for existence
+    #[account(seeds = [b"blacklist", sender.key().as_ref()], bump)]
+    pub sender_blacklist: UncheckedAccount<'info>,
+    /// CHECK: Recipient's blacklist PDA, only checked for existence
+    #[account(seeds = [b"blacklist", recipient_token_account.owner.as_ref()], bump)]
+    pub recipient_blacklist: UncheckedAccount<'info>,
     pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct MintTokens<'info> {
+pub struct SetFeeSplit<'info> {
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"fee_split"], bump)]
+    pub fee_split: Account<'info, FeeSplit>,
+    pub fee_manager: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::FeeManager as u8], fee_manager.key().as_ref()], bump)]
+    pub fee_manager_role: Account<'info, roles::RoleAssignment>,
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddFeeExemption<'info> {
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
     #[account(mut)]
+    pub fee_manager: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::FeeManager as u8], fee_manager.key().as_ref()], bump)]
+    pub fee_manager_role: Account<'info, roles::RoleAssignment>,
+    #[account(
+        init,
+        payer = fee_manager,
+        space = 8 + 32,
+        seeds = [b"fee_exemption", wallet.as_ref()],
+        bump
+    )]
+    pub fee_exemption: Account<'info, FeeExemption>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveFeeExemption<'info> {
+    #[account(seeds = [b"config"], bump)]
     pub config: Account<'info, Config>,
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub fee_manager: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::FeeManager as u8], fee_manager.key().as_ref()], bump)]
+    pub fee_manager_role: Account<'info, roles::RoleAssignment>,
+    #[account(
+        mut,
+        close = fee_manager,
+        seeds = [b"fee_exemption", fee_exemption.wallet.as_ref()],
+        bump
+    )]
+    pub fee_exemption: Account<'info, FeeExemption>,
+}
+
+#[derive(Accounts)]
+pub struct SetFee<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    pub fee_manager: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::FeeManager as u8], fee_manager.key().as_ref()], bump)]
+    pub fee_manager_role: Account<'info, roles::RoleAssignment>,
+}
+
+#[derive(Accounts)]
+pub struct MintTokens<'info> {
+    #[account(mut, seeds = [b"config"], bump, has_one = mint @ StablecoinError::InvalidMint)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"admin_set"], bump)]
+    pub admin_set: Account<'info, multisig::AdminSet>,
+    #[account(mut, seeds = [b"proposal", proposal.index.to_le_bytes().as_ref()], bump)]
+    pub proposal: Account<'info, multisig::Proposal>,
+    pub minter: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::Minter as u8], minter.key().as_ref()], bump)]
+    pub minter_role: Account<'info, roles::RoleAssignment>,
     #[account(mut)]
     pub mint: Account<'info, Mint>,
-    #[account(mut)]
+    #[account(mut, constraint = recipient_token_account.mint == config.mint @ StablecoinError::InvalidMint)]
     pub recipient_token_account: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub mint_authority: Signer<'info>,
+    /// CHECK: Recipient's blacklist PDA, only checked for existence
+    #[account(seeds = [b"blacklist", recipient_token_account.owner.as_ref()], bump)]
+    pub recipient_blacklist: UncheckedAccount<'info>,
+    /// CHECK: This is the PDA for mint authority
+    #[account(seeds = [b"mint_authority"], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+    /// CHECK: USDT price account, parsed by the oracle adapter
+    #[account(address = config.usdt_oracle @ StablecoinError::InvalidOracle)]
+    pub usdt_oracle: UncheckedAccount<'info>,
     pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct BurnTokens<'info> {
+pub struct DepositAndMint<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
     #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(seeds = [b"attestation", user.key().as_ref()], bump)]
+    pub attestation: Account<'info, Attestation>,
+    /// CHECK: User's blacklist PDA, only checked for existence
+    #[account(seeds = [b"blacklist", user.key().as_ref()], bump)]
+    pub user_blacklist: UncheckedAccount<'info>,
+    #[account(address = config.usdt_mint @ StablecoinError::InvalidMint)]
+    pub usdt_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = user_usdt_account.mint == config.usdt_mint @ StablecoinError::InvalidMint,
+        constraint = user_usdt_account.owner == user.key() @ StablecoinError::Unauthorized
+    )]
+    pub user_usdt_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"reserve_vault", usdt_mint.key().as_ref()],
+        bump
+    )]
+    pub usdt_vault: Account<'info, TokenAccount>,
+    #[account(mut, address = config.mint @ StablecoinError::InvalidMint)]
+    pub mint: Account<'info, Mint>,
+    #[account(mut, constraint = user_token_account.mint == config.mint @ StablecoinError::InvalidMint)]
+    pub user_token_account: Account<'info, TokenAccount>,
+    /// CHECK: This is the PDA for mint authority
+    #[account(seeds = [b"mint_authority"], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+    /// CHECK: USDT price account, parsed by the oracle adapter
+    #[account(address = config.usdt_oracle @ StablecoinError::InvalidOracle)]
+    pub usdt_oracle: UncheckedAccount<'info>,
+    #[account(mut, seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Redeem<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    pub holder: Signer<'info>,
+    #[account(seeds = [b"attestation", holder.key().as_ref()], bump)]
+    pub attestation: Account<'info, Attestation>,
+    /// CHECK: Holder's blacklist PDA, only checked for existence
+    #[account(seeds = [b"blacklist", holder.key().as_ref()], bump)]
+    pub holder_blacklist: UncheckedAccount<'info>,
+    #[account(mut, address = config.mint @ StablecoinError::InvalidMint)]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = holder_token_account.mint == config.mint @ StablecoinError::InvalidMint,
+        constraint = holder_token_account.owner == holder.key() @ StablecoinError::Unauthorized
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    #[account(address = config.usdt_mint @ StablecoinError::InvalidMint)]
+    pub usdt_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"reserve_vault", usdt_mint.key().as_ref()],
+        bump
+    )]
+    pub usdt_vault: Account<'info, TokenAccount>,
+    #[account(mut, constraint = holder_usdt_account.mint == config.usdt_mint @ StablecoinError::InvalidMint)]
+    pub holder_usdt_account: Account<'info, TokenAccount>,
+    /// CHECK: This is the PDA that owns the reserve vaults
+    #[account(seeds = [b"vault_authority"], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(mut, seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeReserveVault<'info> {
+    #[account(seeds = [b"config"], bump)]
     pub config: Account<'info, Config>,
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub reserve_manager: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::ReserveManager as u8], reserve_manager.key().as_ref()], bump)]
+    pub reserve_manager_role: Account<'info, roles::RoleAssignment>,
+    #[account(address = config.usdt_mint @ StablecoinError::InvalidMint)]
+    pub usdt_mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = reserve_manager,
+        seeds = [b"reserve_vault", usdt_mint.key().as_ref()],
+        bump,
+        token::mint = usdt_mint,
+        token::authority = vault_authority,
+    )]
+    pub usdt_vault: Account<'info, TokenAccount>,
+    /// CHECK: This is the PDA that owns the reserve vaults
+    #[account(seeds = [b"vault_authority"], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawRevenue<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"admin_set"], bump)]
+    pub admin_set: Account<'info, multisig::AdminSet>,
+    #[account(mut, seeds = [b"proposal", proposal.index.to_le_bytes().as_ref()], bump)]
+    pub proposal: Account<'info, multisig::Proposal>,
+    pub fee_manager: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::FeeManager as u8], fee_manager.key().as_ref()], bump)]
+    pub fee_manager_role: Account<'info, roles::RoleAssignment>,
+    #[account(mut, seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: Account<'info, TokenAccount>,
+    #[account(mut, constraint = destination.mint == config.mint @ StablecoinError::InvalidMint)]
+    pub destination: Account<'info, TokenAccount>,
+    /// CHECK: This is the PDA that owns the reserve vaults
+    #[account(seeds = [b"vault_authority"], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct BurnTokens<'info> {
+    #[account(mut, seeds = [b"config"], bump, has_one = mint @ StablecoinError::InvalidMint)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"admin_set"], bump)]
+    pub admin_set: Account<'info, multisig::AdminSet>,
+    #[account(mut, seeds = [b"proposal", proposal.index.to_le_bytes().as_ref()], bump)]
+    pub proposal: Account<'info, multisig::Proposal>,
+    pub burner: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::Burner as u8], burner.key().as_ref()], bump)]
+    pub burner_role: Account<'info, roles::RoleAssignment>,
+    /// CHECK: This is the PDA for mint authority, approved by the holder as delegate
+    #[account(seeds = [b"mint_authority"], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
     #[account(mut)]
     pub mint: Account<'info, Mint>,
-    #[account(mut)]
+    #[account(mut, constraint = token_account.mint == config.mint @ StablecoinError::InvalidMint)]
     pub token_account: Account<'info, TokenAccount>,
     pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateReserves<'info> {
-    #[account(mut)]
+pub struct SyncReserves<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
     pub config: Account<'info, Config>,
-    #[account(mut)]
-    pub admin: Signer<'info>,
+    pub reserve_manager: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::ReserveManager as u8], reserve_manager.key().as_ref()], bump)]
+    pub reserve_manager_role: Account<'info, roles::RoleAssignment>,
+    #[account(address = config.usdt_mint @ StablecoinError::InvalidMint)]
+    pub usdt_mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"reserve_vault", usdt_mint.key().as_ref()],
+        bump
+    )]
+    pub usdt_vault: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"marketplace"],
+        bump,
+        constraint = marketplace.stablecoin_config == config.key() @ StablecoinError::InvalidMarketplace
+    )]
+    pub marketplace: Account<'info, rwa_marketplace::Marketplace>,
 }
 
 #[derive(Accounts)]
 pub struct UpdateDao<'info> {
-    #[account(mut)]
+    #[account(mut, seeds = [b"config"], bump)]
     pub config: Account<'info, Config>,
     #[account(mut)]
     pub admin: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct SetCollateral<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"admin_set"], bump)]
+    pub admin_set: Account<'info, multisig::AdminSet>,
+    #[account(mut, seeds = [b"proposal", proposal.index.to_le_bytes().as_ref()], bump)]
+    pub proposal: Account<'info, multisig::Proposal>,
+    pub risk_manager: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::RiskManager as u8], risk_manager.key().as_ref()], bump)]
+    pub risk_manager_role: Account<'info, roles::RoleAssignment>,
+}
+
+#[derive(Accounts)]
+pub struct SetUsdtOracle<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    /// CHECK: Parsed as a Pyth-style price account
+    pub usdt_oracle: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetRateLimits<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    pub pauser: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::Pauser as u8], pauser.key().as_ref()], bump)]
+    pub pauser_role: Account<'info, roles::RoleAssignment>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+        constraint = config.pending_admin != Pubkey::default() @ StablecoinError::NoPendingAdmin
+    )]
+    pub config: Account<'info, Config>,
+    #[account(address = config.pending_admin @ StablecoinError::Unauthorized)]
+    pub pending_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeDaoAccount<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(constraint = new_dao_token_account.mint == config.mint @ StablecoinError::InvalidDaoAccount)]
+    pub new_dao_token_account: Account<'info, TokenAccount>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteDaoAccount<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    // Re-validated at execution in case the account changed during the timelock
+    #[account(
+        address = config.pending_dao_account @ StablecoinError::InvalidDaoAccount,
+        constraint = new_dao_token_account.mint == config.mint @ StablecoinError::InvalidDaoAccount
+    )]
+    pub new_dao_token_account: Account<'info, TokenAccount>,
+}
+
 #[derive(Accounts)]
 pub struct GetReserves<'info> {
+    #[account(seeds = [b"config"], bump)]
     pub config: Account<'info, Config>,
+    #[account(address = config.usdt_mint @ StablecoinError::InvalidMint)]
+    pub usdt_mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"reserve_vault", usdt_mint.key().as_ref()],
+        bump
+    )]
+    pub usdt_vault: Account<'info, TokenAccount>,
 }
 
 #[derive(Accounts)]
 pub struct GetDaoContributions<'info> {
+    #[account(seeds = [b"config"], bump)]
     pub config: Account<'info, Config>,
 }
 
@@ -393,7 +2527,31 @@ pub struct TransferEvent {
     pub to: Pubkey,
     pub amount: u64,
     pub fee: u64,              // Fee for green ecosystem initiatives
-    pub dao: Pubkey,           // Green Ecosystem DAO receiving the fee
+}
+
+#[event]
+pub struct FeeUpdatedEvent {
+    pub old_fee_bps: u16,
+    pub fee_bps: u16,
+    pub min_fee: u64,
+    pub fee_tiers: Vec<FeeTier>,
+}
+
+#[event]
+pub struct FeeDistributedEvent {
+    pub token_account: Pubkey,     // DAO account or SDG program receiving its share of the fee
+    pub amount: u64,
+}
+
+#[event]
+pub struct FeeSplitUpdatedEvent {
+    pub beneficiaries: Vec<FeeBeneficiaryWeight>,
+}
+
+#[event]
+pub struct FeeExemptionUpdatedEvent {
+    pub wallet: Pubkey,
+    pub exempt: bool,
 }
 
 #[event]
@@ -408,18 +2566,195 @@ pub struct BurnEvent {
     pub amount: u64,
 }
 
+#[event]
+pub struct RedeemEvent {
+    pub from: Pubkey,
+    pub amount: u64,               // Establo burned
+    pub usdt_amount: u64,          // USDT paid out of the reserve vault
+}
+
+#[event]
+pub struct MintRedeemFeesUpdatedEvent {
+    pub old_mint_fee_bps: u16,
+    pub mint_fee_bps: u16,
+    pub old_redeem_fee_bps: u16,
+    pub redeem_fee_bps: u16,
+}
+
+#[event]
+pub struct MintFeeCollectedEvent {
+    pub payer: Pubkey,
+    pub fee: u64,                  // Establo minted into the revenue vault
+    pub total: u64,                // Total mint fees collected
+}
+
+#[event]
+pub struct RedeemFeeCollectedEvent {
+    pub payer: Pubkey,
+    pub fee: u64,                  // Establo moved into the revenue vault
+    pub total: u64,                // Total redeem fees collected
+}
+
+#[event]
+pub struct RevenueWithdrawnEvent {
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+}
+
+#[event]
+pub struct ConfigMigratedEvent {
+    pub admin: Pubkey,
+    pub total_supply: u64,
+    pub dao_contributions: u64,
+    pub legacy_usdt_reserve: u64,           // Self-reported reserves dropped until the next sync
+    pub legacy_real_estate_value: u64,
+}
+
+#[event]
+pub struct MintAuthorityMigratedEvent {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,     // Mint authority PDA
+}
+
+#[event]
+pub struct FreezeAuthorityMigratedEvent {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,     // Freeze authority PDA
+}
+
+#[event]
+pub struct TokenAccountFrozenEvent {
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub compliance: Pubkey,        // Compliance officer that froze the account
+}
+
+#[event]
+pub struct TokenAccountThawedEvent {
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub compliance: Pubkey,
+}
+
+#[event]
+pub struct BlacklistUpdatedEvent {
+    pub wallet: Pubkey,
+    pub blacklisted: bool,
+    pub compliance: Pubkey,
+}
+
+#[event]
+pub struct AttestationIssuedEvent {
+    pub wallet: Pubkey,
+    pub level: u8,
+    pub expiry: i64,
+    pub jurisdiction: [u8; 2],
+    pub issuer: Pubkey,
+}
+
+#[event]
+pub struct AttestationRevokedEvent {
+    pub wallet: Pubkey,
+    pub compliance: Pubkey,
+}
+
 #[event]
 pub struct ReservesUpdatedEvent {
-    pub usdt_amount: u64,
-    pub real_estate_value: u64,
+    pub old_usdt_reserve: u64,
+    pub usdt_reserve: u64,         // USDT vault balance, in stablecoin base units
+    pub old_real_estate_value: u64,
+    pub real_estate_value: u64,    // Aggregate value of registered properties
+    pub old_haircut_real_estate_value: u64,
+    pub haircut_real_estate_value: u64, // Aggregate value after class haircuts
+    pub slot: u64,
 }
 
 #[event]
 pub struct DaoUpdatedEvent {    
+    pub old_dao_account: Pubkey,
     pub new_dao_account: Pubkey,  // New Green Ecosystem DAO account for SDG initiatives
 }
 
-// Error codes
+#[event]
+pub struct CollateralTargetsUpdatedEvent {
+    pub old_usdt_bps: u16,         // Targets in effect when the ramp started
+    pub old_re_bps: u16,
+    pub target_usdt_bps: u16,
+    pub target_re_bps: u16,
+    pub ramp_end_time: i64,
+}
+
+#[event]
+pub struct CollateralBoundsUpdatedEvent {
+    pub min_usdt_bps: u16,
+    pub max_usdt_bps: u16,
+    pub min_re_bps: u16,
+    pub max_re_bps: u16,
+}
+
+#[event]
+pub struct OracleUpdatedEvent {
+    pub usdt_oracle: Pubkey,
+    pub min_usdt_price_bps: u16,
+    pub max_oracle_staleness: i64,
+}
+
+#[event]
+pub struct RateLimitsUpdatedEvent {
+    pub rate_limit_window: i64,
+    pub mint_limit: u64,
+    pub redeem_limit: u64,
+}
+
+#[event]
+pub struct RateLimitWindowRolledEvent {
+    pub previous_window_start: i64,
+    pub minted: u64,               // Establo minted in the window that just ended
+    pub redeemed: u64,             // Establo redeemed in the window that just ended
+    pub window_start: i64,
+}
+
+#[event]
+pub struct PauseUpdatedEvent {
+    pub old_paused: u8,
+    pub paused: u8,
+    pub pauser: Pubkey,
+}
+
+#[event]
+pub struct AdminProposedEvent {
+    pub current_admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminUpdatedEvent {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct DaoUpdateProposedEvent {
+    pub current_dao_account: Pubkey,
+    pub pending_dao_account: Pubkey,
+    pub eta: i64,                 // Earliest execution time
+}
+
+#[event]
+pub struct DaoUpdateCancelledEvent {
+    pub cancelled_dao_account: Pubkey,
+}
+
+#[event]
+pub struct DaoTimelockUpdatedEvent {
+    pub old_dao_timelock: i64,
+    pub dao_timelock: i64,
+}
+
+// Error codes 6000-6099; each module's error enum sets its own range of 100 with `offset`:
+// RWAMarketplaceError 6100, MultisigError 6200, RoleError 6300, AuditError 6400,
+// OracleError 6500, PsmError 6600
 #[error_code]
 pub enum StablecoinError {
     #[msg("Unauthorized access")]
@@ -432,4 +2767,60 @@ pub enum StablecoinError {
     Overflow,
     #[msg("Insufficient amount after fee")]
     InsufficientAmount,
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("Invalid token mint")]
+    InvalidMint,
+    #[msg("Fee exceeds protocol maximum")]
+    InvalidFee,
+    #[msg("Fee tiers must be sorted and within the tier limit")]
+    InvalidFeeTiers,
+    #[msg("Fee split weights must be non-zero and sum to 10,000")]
+    InvalidFeeSplit,
+    #[msg("Fee beneficiary account does not match the fee split")]
+    InvalidFeeBeneficiary,
+    #[msg("Marketplace is not linked to this stablecoin config")]
+    InvalidMarketplace,
+    #[msg("No DAO account change is pending")]
+    NoPendingDaoAccount,
+    #[msg("DAO account timelock has not elapsed")]
+    DaoTimelockNotElapsed,
+    #[msg("Timelock is below the protocol minimum")]
+    InvalidTimelock,
+    #[msg("No admin handover is pending")]
+    NoPendingAdmin,
+    #[msg("Operation is paused")]
+    Paused,
+    #[msg("Unknown pause flags")]
+    InvalidPauseFlags,
+    #[msg("Rate limit exceeded for the current window")]
+    RateLimitExceeded,
+    #[msg("Rate limit window must be greater than zero")]
+    InvalidRateLimit,
+    #[msg("Wallet is blacklisted")]
+    Blacklisted,
+    #[msg("KYC attestation has expired")]
+    AttestationExpired,
+    #[msg("KYC attestation level is too low")]
+    InsufficientAttestationLevel,
+    #[msg("Oracle account does not match the configured USDT oracle")]
+    InvalidOracle,
+    #[msg("USDT price floor must be at most 10,000 bps and staleness positive")]
+    InvalidOracleConfig,
+    #[msg("Collateral targets must be within bounds and sum to at least 10,000 bps")]
+    InvalidCollateralTargets,
+    #[msg("Collateral bounds are inconsistent with each other or the current targets")]
+    InvalidCollateralBounds,
+    #[msg("Collateral ramp is shorter than the protocol minimum")]
+    InvalidRampDuration,
+    #[msg("Account is already in the current layout")]
+    AlreadyMigrated,
+    #[msg("Account is not a legacy account of this program")]
+    InvalidLegacyAccount,
+    #[msg("A DAO account change is already pending")]
+    DaoUpdatePending,
+    #[msg("Decimals do not match the stablecoin mint")]
+    InvalidDecimals,
+    #[msg("Token account has not delegated the burn amount to the mint authority")]
+    BurnNotDelegated,
 }
\ No newline at end of file
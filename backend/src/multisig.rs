@@ -0,0 +1,361 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_SIGNERS: usize = 10;     // Maximum number of signers in the admin set
+pub const PROPOSAL_TTL: i64 = 7 * 24 * 60 * 60; // Proposals lapse a week after they are created
+
+// Privileged actions that require M-of-N approval before they can be executed
+// Parameters are bound into the proposal so an approval can't be reused for a different action
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum PrivilegedAction {
+    Mint {
+        recipient_token_account: Pubkey,
+        amount: u64,
+    },
+    Burn {
+        token_account: Pubkey,
+        amount: u64,
+    },
+    LiquidateRwa {
+        property: Pubkey,
+    },
+    UpdateAdminSet {
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    },
+    WithdrawRevenue {
+        destination: Pubkey,
+        amount: u64,
+    },
+    SetCollateralTargets {
+        target_usdt_bps: u16,
+        target_re_bps: u16,
+        ramp_duration: i64,
+    },
+    SetCollateralBounds {
+        min_usdt_bps: u16,
+        max_usdt_bps: u16,
+        min_re_bps: u16,
+        max_re_bps: u16,
+    },
+    ConfigurePsm {
+        tin_bps: u16,
+        tout_bps: u16,
+        debt_ceiling: u64,
+    },
+}
+
+// Serialized size of the largest action (UpdateAdminSet with a full signer list)
+pub const MAX_ACTION_LEN: usize = 1 + 4 + MAX_SIGNERS * 32 + 1;
+
+// Create the admin set once, from the stablecoin admin
+pub fn initialize_admin_set(
+    ctx: Context<InitializeAdminSet>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.config.admin,
+        MultisigError::Unauthorized
+    );
+    validate_signers(&signers, threshold)?;
+
+    let admin_set = &mut ctx.accounts.admin_set;
+    admin_set.signers = signers.clone();
+    admin_set.threshold = threshold;
+    admin_set.proposal_count = 0;
+
+    // Emit event
+    emit!(AdminSetUpdatedEvent { signers, threshold });
+
+    Ok(())
+}
+
+// Replace the signers and threshold through an approved proposal
+pub fn update_admin_set(ctx: Context<UpdateAdminSet>) -> Result<()> {
+    let (signers, threshold) = match &ctx.accounts.proposal.action {
+        PrivilegedAction::UpdateAdminSet { signers, threshold } => (signers.clone(), *threshold),
+        _ => return Err(error!(MultisigError::ActionMismatch)),
+    };
+    validate_signers(&signers, threshold)?;
+
+    let action = ctx.accounts.proposal.action.clone();
+    execute_proposal(&ctx.accounts.admin_set, &mut ctx.accounts.proposal, &action)?;
+
+    let admin_set = &mut ctx.accounts.admin_set;
+    admin_set.signers = signers.clone();
+    admin_set.threshold = threshold;
+
+    // Emit event
+    emit!(AdminSetUpdatedEvent { signers, threshold });
+
+    Ok(())
+}
+
+// Propose a privileged action; the proposer's approval is recorded immediately
+pub fn propose_action(ctx: Context<ProposeAction>, action: PrivilegedAction) -> Result<()> {
+    let admin_set = &mut ctx.accounts.admin_set;
+    let proposer = ctx.accounts.proposer.key();
+    require!(admin_set.signers.contains(&proposer), MultisigError::NotASigner);
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.index = admin_set.proposal_count;
+    proposal.proposer = proposer;
+    proposal.action = action;
+    proposal.approvals = vec![proposer];
+    proposal.executed = false;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.expires_at = proposal
+        .created_at
+        .checked_add(PROPOSAL_TTL)
+        .ok_or(MultisigError::Overflow)?;
+
+    admin_set.proposal_count = admin_set
+        .proposal_count
+        .checked_add(1)
+        .ok_or(MultisigError::Overflow)?;
+
+    // Emit event
+    emit!(ActionProposedEvent {
+        index: proposal.index,
+        proposer,
+    });
+
+    Ok(())
+}
+
+// Approve a pending proposal
+pub fn approve_action(ctx: Context<ApproveAction>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    require!(
+        ctx.accounts.admin_set.signers.contains(&signer),
+        MultisigError::NotASigner
+    );
+
+    let proposal = &mut ctx.accounts.proposal;
+    require!(!proposal.executed, MultisigError::AlreadyExecuted);
+    require!(
+        Clock::get()?.unix_timestamp < proposal.expires_at,
+        MultisigError::ProposalExpired
+    );
+    require!(!proposal.approvals.contains(&signer), MultisigError::AlreadyApproved);
+    proposal.approvals.push(signer);
+
+    // Emit event
+    emit!(ActionApprovedEvent {
+        index: proposal.index,
+        signer,
+        approvals: proposal.approvals.len() as u8,
+    });
+
+    Ok(())
+}
+
+// Cancel a pending proposal; any current signer can withdraw it, and its rent returns to the proposer
+pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    require!(
+        ctx.accounts.admin_set.signers.contains(&signer),
+        MultisigError::NotASigner
+    );
+    require!(!ctx.accounts.proposal.executed, MultisigError::AlreadyExecuted);
+
+    // Emit event
+    emit!(ProposalCancelledEvent {
+        index: ctx.accounts.proposal.index,
+        signer,
+    });
+
+    Ok(())
+}
+
+// Consume an approved proposal for `action`; called by each privileged instruction
+pub fn execute_proposal(
+    admin_set: &AdminSet,
+    proposal: &mut Proposal,
+    action: &PrivilegedAction,
+) -> Result<()> {
+    require!(!proposal.executed, MultisigError::AlreadyExecuted);
+    require!(
+        Clock::get()?.unix_timestamp < proposal.expires_at,
+        MultisigError::ProposalExpired
+    );
+    require!(proposal.action == *action, MultisigError::ActionMismatch);
+
+    // Only approvals from current signers count, in case the set changed since approval
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|approver| admin_set.signers.contains(approver))
+        .count();
+    require!(
+        approvals >= admin_set.threshold as usize,
+        MultisigError::NotEnoughApprovals
+    );
+
+    proposal.executed = true;
+
+    // Emit event
+    emit!(ActionExecutedEvent {
+        index: proposal.index,
+    });
+
+    Ok(())
+}
+
+// Helper function to validate a signer list and threshold
+fn validate_signers(signers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !signers.is_empty() && signers.len() <= MAX_SIGNERS,
+        MultisigError::InvalidSigners
+    );
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        MultisigError::InvalidThreshold
+    );
+    for (i, signer) in signers.iter().enumerate() {
+        require!(!signers[..i].contains(signer), MultisigError::InvalidSigners);
+    }
+    Ok(())
+}
+
+// Account and structure definitions
+#[account]
+pub struct AdminSet {
+    pub signers: Vec<Pubkey>,             // Keys allowed to propose and approve
+    pub threshold: u8,                    // Approvals required to execute (M of N)
+    pub proposal_count: u64,              // Number of proposals created, used as the next index
+}
+
+#[account]
+pub struct Proposal {
+    pub index: u64,                       // Proposal index, part of the PDA seeds
+    pub proposer: Pubkey,
+    pub action: PrivilegedAction,         // Action and parameters being approved
+    pub approvals: Vec<Pubkey>,           // Signers that approved
+    pub executed: bool,                   // Proposals can only be executed once
+    pub created_at: i64,
+    pub expires_at: i64,                  // No approvals or execution from this time on
+}
+
+#[derive(Accounts)]
+pub struct InitializeAdminSet<'info> {
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, crate::Config>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 4 + MAX_SIGNERS * 32 + 1 + 8,
+        seeds = [b"admin_set"],
+        bump
+    )]
+    pub admin_set: Account<'info, AdminSet>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAdminSet<'info> {
+    #[account(mut, seeds = [b"admin_set"], bump)]
+    pub admin_set: Account<'info, AdminSet>,
+    #[account(mut, seeds = [b"proposal", proposal.index.to_le_bytes().as_ref()], bump)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAction<'info> {
+    #[account(mut, seeds = [b"admin_set"], bump)]
+    pub admin_set: Account<'info, AdminSet>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + 8 + 32 + MAX_ACTION_LEN + 4 + MAX_SIGNERS * 32 + 1 + 8 + 8,
+        seeds = [b"proposal", admin_set.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveAction<'info> {
+    #[account(seeds = [b"admin_set"], bump)]
+    pub admin_set: Account<'info, AdminSet>,
+    #[account(mut, seeds = [b"proposal", proposal.index.to_le_bytes().as_ref()], bump)]
+    pub proposal: Account<'info, Proposal>,
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(seeds = [b"admin_set"], bump)]
+    pub admin_set: Account<'info, AdminSet>,
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.index.to_le_bytes().as_ref()],
+        bump,
+        close = proposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: Receives the proposal rent, checked against the proposal
+    #[account(mut, address = proposal.proposer @ MultisigError::Unauthorized)]
+    pub proposer: UncheckedAccount<'info>,
+    pub signer: Signer<'info>,
+}
+
+// Events
+#[event]
+pub struct AdminSetUpdatedEvent {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ActionProposedEvent {
+    pub index: u64,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct ActionApprovedEvent {
+    pub index: u64,
+    pub signer: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct ActionExecutedEvent {
+    pub index: u64,
+}
+
+#[event]
+pub struct ProposalCancelledEvent {
+    pub index: u64,
+    pub signer: Pubkey,
+}
+
+#[error_code(offset = 6200)]
+pub enum MultisigError {
+    #[msg("Unauthorized access")]
+    Unauthorized,
+    #[msg("Signer is not part of the admin set")]
+    NotASigner,
+    #[msg("Signer list must be non-empty, unique and within the signer limit")]
+    InvalidSigners,
+    #[msg("Threshold must be between 1 and the number of signers")]
+    InvalidThreshold,
+    #[msg("Proposal already approved by this signer")]
+    AlreadyApproved,
+    #[msg("Proposal already executed")]
+    AlreadyExecuted,
+    #[msg("Proposal does not match the requested action")]
+    ActionMismatch,
+    #[msg("Proposal does not have enough approvals")]
+    NotEnoughApprovals,
+    #[msg("Arithmetic overflow")]
+    Overflow,
+    #[msg("Proposal has expired")]
+    ProposalExpired,
+}
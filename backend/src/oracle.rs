@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+// Pyth oracle program on mainnet; only price accounts it owns are trusted
+pub const PYTH_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH");
+
+// Widest confidence interval accepted, relative to the price (basis points)
+pub const MAX_CONF_BPS: u64 = 100;
+
+// Pyth v2 price account layout (little-endian)
+pub const PYTH_MAGIC: u32 = 0xa1b2_c3d4;
+pub const PYTH_PRICE_ACCOUNT_TYPE: u32 = 3;
+pub const PYTH_STATUS_TRADING: u32 = 1;
+pub const PYTH_MAGIC_OFFSET: usize = 0;
+pub const PYTH_ACCOUNT_TYPE_OFFSET: usize = 8;
+pub const PYTH_EXPO_OFFSET: usize = 20;
+pub const PYTH_TIMESTAMP_OFFSET: usize = 96;
+pub const PYTH_AGG_PRICE_OFFSET: usize = 208;
+pub const PYTH_AGG_CONF_OFFSET: usize = 216;
+pub const PYTH_AGG_STATUS_OFFSET: usize = 224;
+pub const PYTH_PRICE_ACCOUNT_MIN_LEN: usize = 240;
+
+// Aggregate price read from an oracle price account
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OraclePrice {
+    pub price: i64,                       // Price in units of 10^expo
+    pub conf: u64,                        // Confidence interval, same units as `price`
+    pub expo: i32,
+    pub publish_time: i64,                // Timestamp of the aggregate price
+}
+
+impl OraclePrice {
+    // Price in basis points of one quote unit (10,000 = $1.00), zero for non-positive prices
+    pub fn price_bps(&self) -> u64 {
+        if self.price <= 0 {
+            return 0;
+        }
+        let scaled = self.price as u128 * 10_000;
+        let bps = if self.expo < 0 {
+            10u128
+                .checked_pow(self.expo.unsigned_abs())
+                .map(|factor| scaled / factor)
+                .unwrap_or(0)
+        } else {
+            10u128
+                .checked_pow(self.expo as u32)
+                .and_then(|factor| scaled.checked_mul(factor))
+                .unwrap_or(u128::MAX)
+        };
+        u64::try_from(bps).unwrap_or(u64::MAX)
+    }
+}
+
+// Parse the aggregate price of a Pyth-style price account
+// Only trading prices are accepted; halted or unknown feeds are rejected
+pub fn parse_price_account(data: &[u8]) -> Result<OraclePrice> {
+    require!(
+        data.len() >= PYTH_PRICE_ACCOUNT_MIN_LEN,
+        OracleError::InvalidOracleAccount
+    );
+    require!(
+        read_u32(data, PYTH_MAGIC_OFFSET) == PYTH_MAGIC
+            && read_u32(data, PYTH_ACCOUNT_TYPE_OFFSET) == PYTH_PRICE_ACCOUNT_TYPE,
+        OracleError::InvalidOracleAccount
+    );
+    require!(
+        read_u32(data, PYTH_AGG_STATUS_OFFSET) == PYTH_STATUS_TRADING,
+        OracleError::OracleNotTrading
+    );
+
+    Ok(OraclePrice {
+        price: read_u64(data, PYTH_AGG_PRICE_OFFSET) as i64,
+        conf: read_u64(data, PYTH_AGG_CONF_OFFSET),
+        expo: read_u32(data, PYTH_EXPO_OFFSET) as i32,
+        publish_time: read_u64(data, PYTH_TIMESTAMP_OFFSET) as i64,
+    })
+}
+
+// Parse a price account after checking that the Pyth program owns it
+pub fn load_price_account(oracle: &AccountInfo) -> Result<OraclePrice> {
+    require_keys_eq!(*oracle.owner, PYTH_PROGRAM_ID, OracleError::InvalidOracleOwner);
+    parse_price_account(&oracle.try_borrow_data()?)
+}
+
+// Read the USDT price and check it is fresh, precise and above the configured floor
+// Returns the price in basis points of $1
+pub fn usdt_price_bps(oracle: &AccountInfo, config: &crate::Config) -> Result<u64> {
+    let price = load_price_account(oracle)?;
+
+    let age = Clock::get()?.unix_timestamp.saturating_sub(price.publish_time);
+    require!(age <= config.max_oracle_staleness, OracleError::StaleOraclePrice);
+    // Publishers that disagree widely don't give a usable price, even if the aggregate looks pegged
+    require!(
+        price.conf as u128 * 10_000 <= price.price.max(0) as u128 * MAX_CONF_BPS as u128,
+        OracleError::ConfidenceTooWide
+    );
+
+    let price_bps = price.price_bps();
+    require!(
+        price_bps >= config.min_usdt_price_bps as u64,
+        OracleError::UsdtDepegged
+    );
+    Ok(price_bps)
+}
+
+// Helper functions to read little-endian integers at a fixed offset
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&data[offset..offset + 4]);
+    u32::from_le_bytes(bytes)
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&data[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+#[error_code(offset = 6500)]
+pub enum OracleError {
+    #[msg("Oracle account is not a price account")]
+    InvalidOracleAccount,
+    #[msg("Oracle price is not trading")]
+    OracleNotTrading,
+    #[msg("Oracle price is stale")]
+    StaleOraclePrice,
+    #[msg("USDT price is below the configured floor")]
+    UsdtDepegged,
+    #[msg("Oracle account is not owned by the Pyth program")]
+    InvalidOracleOwner,
+    #[msg("Oracle confidence interval is too wide")]
+    ConfidenceTooWide,
+}
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::{normalize_amount, Config, StablecoinError};
+
+// Record the current backing as the next link of the snapshot chain
+// Permissionless: every field is read from on-chain state
+pub fn snapshot_reserves(ctx: Context<SnapshotReserves>) -> Result<()> {
+    let usdt_vault_balance = normalize_amount(
+        ctx.accounts.usdt_vault.amount,
+        ctx.accounts.usdt_mint.decimals,
+        ctx.accounts.config.decimals,
+    )?;
+    let clock = Clock::get()?;
+
+    let config = &mut ctx.accounts.config;
+    let snapshot = &mut ctx.accounts.snapshot;
+    snapshot.index = config.snapshot_count;
+    snapshot.slot = clock.slot;
+    snapshot.timestamp = clock.unix_timestamp;
+    snapshot.total_supply = config.total_supply;
+    snapshot.usdt_vault_balance = usdt_vault_balance;
+    snapshot.real_estate_value = config.real_estate_value;
+    snapshot.prev_hash = config.last_snapshot_hash;
+    snapshot.hash = snapshot_hash(snapshot);
+
+    config.snapshot_count = config
+        .snapshot_count
+        .checked_add(1)
+        .ok_or(StablecoinError::Overflow)?;
+    config.last_snapshot_hash = snapshot.hash;
+
+    // Emit event
+    emit!(ReserveSnapshotEvent {
+        index: snapshot.index,
+        slot: snapshot.slot,
+        hash: snapshot.hash,
+    });
+
+    Ok(())
+}
+
+// Hash of a snapshot's contents, chained to the previous snapshot through `prev_hash`
+pub fn snapshot_hash(snapshot: &ReserveSnapshot) -> [u8; 32] {
+    hashv(&[
+        &snapshot.index.to_le_bytes(),
+        &snapshot.slot.to_le_bytes(),
+        &snapshot.timestamp.to_le_bytes(),
+        &snapshot.total_supply.to_le_bytes(),
+        &snapshot.usdt_vault_balance.to_le_bytes(),
+        &snapshot.real_estate_value.to_le_bytes(),
+        &snapshot.prev_hash,
+    ])
+    .to_bytes()
+}
+
+// Problems found while walking a snapshot chain
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChainIssue {
+    Gap { expected: u64, found: u64 },    // Snapshots are missing before `found`
+    HashMismatch { index: u64 },          // Snapshot contents don't match its stored hash
+    BrokenLink { index: u64 },            // `prev_hash` doesn't match the previous snapshot
+}
+
+// Off-chain verifier: walk snapshots sorted by index and report every gap or tampered link
+// The chain may start at any index; only a chain starting at 0 has a known (zeroed) predecessor
+pub fn verify_snapshot_chain(snapshots: &[ReserveSnapshot]) -> Vec<ChainIssue> {
+    let mut issues = Vec::new();
+    let mut previous: Option<&ReserveSnapshot> = None;
+
+    for snapshot in snapshots {
+        if snapshot_hash(snapshot) != snapshot.hash {
+            issues.push(ChainIssue::HashMismatch { index: snapshot.index });
+        }
+
+        match previous {
+            Some(previous) => {
+                let expected = previous.index + 1;
+                if snapshot.index != expected {
+                    issues.push(ChainIssue::Gap {
+                        expected,
+                        found: snapshot.index,
+                    });
+                }
+                if snapshot.prev_hash != previous.hash {
+                    issues.push(ChainIssue::BrokenLink { index: snapshot.index });
+                }
+            }
+            None if snapshot.index == 0 && snapshot.prev_hash != [0u8; 32] => {
+                issues.push(ChainIssue::BrokenLink { index: 0 });
+            }
+            None => {}
+        }
+
+        previous = Some(snapshot);
+    }
+
+    issues
+}
+
+// Account and structure definitions
+#[account]
+#[derive(Default)]
+pub struct ReserveSnapshot {
+    pub index: u64,                       // Position in the chain, part of the PDA seeds
+    pub slot: u64,
+    pub timestamp: i64,
+    pub total_supply: u64,                // Outstanding Establo supply
+    pub usdt_vault_balance: u64,          // USDT reserve vault balance, in stablecoin base units
+    pub real_estate_value: u64,           // Aggregate real estate value
+    pub prev_hash: [u8; 32],              // Hash of the previous snapshot, zeroed for the first
+    pub hash: [u8; 32],                   // Hash of this snapshot's contents and `prev_hash`
+}
+
+#[derive(Accounts)]
+pub struct SnapshotReserves<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    #[account(address = config.usdt_mint @ StablecoinError::InvalidMint)]
+    pub usdt_mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"reserve_vault", usdt_mint.key().as_ref()],
+        bump
+    )]
+    pub usdt_vault: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 8 + 8 + 8 + 8 + 8 + 8 + 32 + 32,
+        seeds = [b"reserve_snapshot", config.snapshot_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub snapshot: Account<'info, ReserveSnapshot>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Events
+#[event]
+pub struct ReserveSnapshotEvent {
+    pub index: u64,
+    pub slot: u64,
+    pub hash: [u8; 32],
+}
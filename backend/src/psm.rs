@@ -0,0 +1,413 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::multisig::{self, AdminSet, PrivilegedAction, Proposal};
+use crate::roles::{Role, RoleAssignment};
+use crate::{
+    check_attestation, consume_mint_limit, consume_redeem_limit, is_backed, normalize_amount,
+    oracle, pda_exists, protocol_fee, Attestation, Config, StablecoinError, MAX_FEE_BPS,
+    PAUSE_MINT, PAUSE_REDEEM,
+};
+
+// Peg stability module: swap USDT for Establo 1:1 (decimal-normalized)
+// USDT is the only collateral. Swaps go through the USDT reserve vault, so they are priced
+// and backed exactly like deposits; the PSM adds its own tin/tout fees and a single debt
+// ceiling on the Establo it has issued
+
+// Create the PSM through an approved multisig proposal (ReserveManager)
+pub fn initialize_psm(
+    ctx: Context<InitializePsm>,
+    tin_bps: u16,
+    tout_bps: u16,
+    debt_ceiling: u64,
+) -> Result<()> {
+    require!(tin_bps <= MAX_FEE_BPS && tout_bps <= MAX_FEE_BPS, PsmError::InvalidFee);
+    let action = PrivilegedAction::ConfigurePsm {
+        tin_bps,
+        tout_bps,
+        debt_ceiling,
+    };
+    multisig::execute_proposal(&ctx.accounts.admin_set, &mut ctx.accounts.proposal, &action)?;
+
+    let psm = &mut ctx.accounts.psm;
+    psm.tin_bps = tin_bps;
+    psm.tout_bps = tout_bps;
+    psm.debt_ceiling = debt_ceiling;
+    psm.debt = 0;
+    psm.fee_revenue = 0;
+
+    // Emit event
+    emit!(PsmParamsUpdatedEvent {
+        tin_bps,
+        tout_bps,
+        debt_ceiling,
+    });
+
+    Ok(())
+}
+
+// Update the PSM fees and debt ceiling through an approved multisig proposal (ReserveManager)
+// Lowering the ceiling below the current debt only blocks further swaps in
+pub fn set_psm_params(
+    ctx: Context<SetPsmParams>,
+    tin_bps: u16,
+    tout_bps: u16,
+    debt_ceiling: u64,
+) -> Result<()> {
+    require!(tin_bps <= MAX_FEE_BPS && tout_bps <= MAX_FEE_BPS, PsmError::InvalidFee);
+    let action = PrivilegedAction::ConfigurePsm {
+        tin_bps,
+        tout_bps,
+        debt_ceiling,
+    };
+    multisig::execute_proposal(&ctx.accounts.admin_set, &mut ctx.accounts.proposal, &action)?;
+
+    let psm = &mut ctx.accounts.psm;
+    psm.tin_bps = tin_bps;
+    psm.tout_bps = tout_bps;
+    psm.debt_ceiling = debt_ceiling;
+
+    // Emit event
+    emit!(PsmParamsUpdatedEvent {
+        tin_bps,
+        tout_bps,
+        debt_ceiling,
+    });
+
+    Ok(())
+}
+
+// Swap USDT for Establo at par, less the tin fee
+// Halts with deposits when the USDT price is stale or depegged; the fee is minted into the revenue vault
+pub fn swap_in(ctx: Context<SwapIn>, collateral_amount: u64) -> Result<()> {
+    require!(ctx.accounts.config.paused & PAUSE_MINT == 0, StablecoinError::Paused);
+    require!(!pda_exists(&ctx.accounts.user_blacklist), StablecoinError::Blacklisted);
+    check_attestation(&ctx.accounts.attestation)?;
+    let usdt_price_bps = oracle::usdt_price_bps(&ctx.accounts.usdt_oracle, &ctx.accounts.config)?;
+    require!(collateral_amount > 0, PsmError::InvalidAmount);
+
+    // Convert the collateral into Establo base units
+    let amount = normalize_amount(
+        collateral_amount,
+        ctx.accounts.collateral_mint.decimals,
+        ctx.accounts.config.decimals,
+    )?;
+    require!(amount > 0, PsmError::InvalidAmount);
+
+    let psm = &mut ctx.accounts.psm;
+    let debt = psm.debt.checked_add(amount).ok_or(PsmError::Overflow)?;
+    require!(debt <= psm.debt_ceiling, PsmError::DebtCeilingExceeded);
+    let fee = protocol_fee(amount, psm.tin_bps);
+    let amount_after_fee = amount - fee;
+    require!(amount_after_fee > 0, PsmError::InvalidAmount);
+    psm.debt = debt;
+    psm.fee_revenue = psm.fee_revenue.checked_add(fee).ok_or(PsmError::Overflow)?;
+
+    let config = &mut ctx.accounts.config;
+    consume_mint_limit(config, amount)?;
+    config.usdt_reserve = config
+        .usdt_reserve
+        .checked_add(amount)
+        .ok_or(PsmError::Overflow)?;
+    require!(
+        is_backed(config, usdt_price_bps, amount, Clock::get()?.unix_timestamp),
+        StablecoinError::InsufficientReserves
+    );
+    config.total_supply = config
+        .total_supply
+        .checked_add(amount)
+        .ok_or(PsmError::Overflow)?;
+
+    // Move the collateral into its reserve vault
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.user_collateral_account.to_account_info(),
+        to: ctx.accounts.collateral_vault.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts);
+    token::transfer(cpi_ctx, collateral_amount)?;
+
+    // Mint Establo to the user and the fee to the revenue vault, signed by the mint authority PDA
+    let bump = *ctx.bumps.get("mint_authority").unwrap();
+    let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[bump]];
+    let signer = &[mint_authority_seeds];
+    let cpi_accounts = token::MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.user_token_account.to_account_info(),
+        authority: ctx.accounts.mint_authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer);
+    token::mint_to(cpi_ctx, amount_after_fee)?;
+
+    if fee > 0 {
+        let cpi_accounts = token::MintTo {
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.revenue_vault.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::mint_to(cpi_ctx, fee)?;
+    }
+
+    // Emit event
+    emit!(PsmSwapEvent {
+        user: ctx.accounts.user.key(),
+        collateral_amount,
+        amount: amount_after_fee,
+        fee,
+        swap_in: true,
+        debt: ctx.accounts.psm.debt,
+    });
+
+    Ok(())
+}
+
+// Swap Establo for USDT at par, less the tout fee
+// The fee stays in Establo in the protocol revenue vault; the rest is burned
+pub fn swap_out(ctx: Context<SwapOut>, amount: u64) -> Result<()> {
+    require!(ctx.accounts.config.paused & PAUSE_REDEEM == 0, StablecoinError::Paused);
+    require!(!pda_exists(&ctx.accounts.user_blacklist), StablecoinError::Blacklisted);
+    check_attestation(&ctx.accounts.attestation)?;
+    require!(amount > 0, PsmError::InvalidAmount);
+
+    let psm = &mut ctx.accounts.psm;
+    let fee = protocol_fee(amount, psm.tout_bps);
+    let amount_after_fee = amount - fee;
+
+    // Convert the Establo into collateral base units
+    let collateral_amount = normalize_amount(
+        amount_after_fee,
+        ctx.accounts.config.decimals,
+        ctx.accounts.collateral_mint.decimals,
+    )?;
+    require!(collateral_amount > 0, PsmError::InvalidAmount);
+    require!(
+        ctx.accounts.collateral_vault.amount >= collateral_amount,
+        PsmError::InsufficientCollateral
+    );
+
+    // Only debt this PSM issued can be swapped back out of it
+    psm.debt = psm
+        .debt
+        .checked_sub(amount_after_fee)
+        .ok_or(PsmError::InsufficientCollateral)?;
+    psm.fee_revenue = psm.fee_revenue.checked_add(fee).ok_or(PsmError::Overflow)?;
+
+    // The window counts the gross amount, as swaps in do, fee included
+    let config = &mut ctx.accounts.config;
+    consume_redeem_limit(config, amount)?;
+    config.usdt_reserve = config
+        .usdt_reserve
+        .checked_sub(amount_after_fee)
+        .ok_or(PsmError::InsufficientCollateral)?;
+    config.total_supply = config
+        .total_supply
+        .checked_sub(amount_after_fee)
+        .ok_or(PsmError::Overflow)?;
+
+    // Move the fee into the protocol revenue vault
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    if fee > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.user_token_account.to_account_info(),
+            to: ctx.accounts.revenue_vault.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts);
+        token::transfer(cpi_ctx, fee)?;
+    }
+
+    // Burn the rest of the user's Establo
+    let cpi_accounts = token::Burn {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.user_token_account.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts);
+    token::burn(cpi_ctx, amount_after_fee)?;
+
+    // Pay the collateral out of its reserve vault, signed by the vault authority PDA
+    let bump = *ctx.bumps.get("vault_authority").unwrap();
+    let vault_authority_seeds: &[&[u8]] = &[b"vault_authority", &[bump]];
+    let signer = &[vault_authority_seeds];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.collateral_vault.to_account_info(),
+        to: ctx.accounts.user_collateral_account.to_account_info(),
+        authority: ctx.accounts.vault_authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, collateral_amount)?;
+
+    // Emit event
+    emit!(PsmSwapEvent {
+        user: ctx.accounts.user.key(),
+        collateral_amount,
+        amount: amount_after_fee,
+        fee,
+        swap_in: false,
+        debt: ctx.accounts.psm.debt,
+    });
+
+    Ok(())
+}
+
+// Account and structure definitions
+#[account]
+#[derive(Default)]
+pub struct Psm {
+    pub tin_bps: u16,                     // Fee on swaps in (basis points)
+    pub tout_bps: u16,                    // Fee on swaps out (basis points)
+    pub debt_ceiling: u64,                // Maximum Establo outstanding through the PSM
+    pub debt: u64,                        // Establo currently outstanding through the PSM
+    pub fee_revenue: u64,                 // Total tin/tout fees collected
+}
+
+#[derive(Accounts)]
+pub struct InitializePsm<'info> {
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"admin_set"], bump)]
+    pub admin_set: Account<'info, AdminSet>,
+    #[account(mut, seeds = [b"proposal", proposal.index.to_le_bytes().as_ref()], bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        init,
+        payer = reserve_manager,
+        space = 8 + 2 + 2 + 8 + 8 + 8,
+        seeds = [b"psm"],
+        bump
+    )]
+    pub psm: Account<'info, Psm>,
+    #[account(mut)]
+    pub reserve_manager: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::ReserveManager as u8], reserve_manager.key().as_ref()], bump)]
+    pub reserve_manager_role: Account<'info, RoleAssignment>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPsmParams<'info> {
+    #[account(seeds = [b"admin_set"], bump)]
+    pub admin_set: Account<'info, AdminSet>,
+    #[account(mut, seeds = [b"proposal", proposal.index.to_le_bytes().as_ref()], bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut, seeds = [b"psm"], bump)]
+    pub psm: Account<'info, Psm>,
+    pub reserve_manager: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::ReserveManager as u8], reserve_manager.key().as_ref()], bump)]
+    pub reserve_manager_role: Account<'info, RoleAssignment>,
+}
+
+#[derive(Accounts)]
+pub struct SwapIn<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"psm"], bump)]
+    pub psm: Account<'info, Psm>,
+    pub user: Signer<'info>,
+    #[account(seeds = [b"attestation", user.key().as_ref()], bump)]
+    pub attestation: Account<'info, Attestation>,
+    /// CHECK: User's blacklist PDA, only checked for existence
+    #[account(seeds = [b"blacklist", user.key().as_ref()], bump)]
+    pub user_blacklist: UncheckedAccount<'info>,
+    #[account(address = config.usdt_mint @ PsmError::UnsupportedCollateral)]
+    pub collateral_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = user_collateral_account.mint == collateral_mint.key() @ StablecoinError::InvalidMint,
+        constraint = user_collateral_account.owner == user.key() @ StablecoinError::Unauthorized
+    )]
+    pub user_collateral_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"reserve_vault", collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    #[account(mut, address = config.mint @ StablecoinError::InvalidMint)]
+    pub mint: Account<'info, Mint>,
+    #[account(mut, constraint = user_token_account.mint == config.mint @ StablecoinError::InvalidMint)]
+    pub user_token_account: Account<'info, TokenAccount>,
+    #[account(mut, seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: Account<'info, TokenAccount>,
+    /// CHECK: This is the PDA for mint authority
+    #[account(seeds = [b"mint_authority"], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+    /// CHECK: USDT price account, parsed by the oracle adapter
+    #[account(address = config.usdt_oracle @ StablecoinError::InvalidOracle)]
+    pub usdt_oracle: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SwapOut<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"psm"], bump)]
+    pub psm: Account<'info, Psm>,
+    pub user: Signer<'info>,
+    #[account(seeds = [b"attestation", user.key().as_ref()], bump)]
+    pub attestation: Account<'info, Attestation>,
+    /// CHECK: User's blacklist PDA, only checked for existence
+    #[account(seeds = [b"blacklist", user.key().as_ref()], bump)]
+    pub user_blacklist: UncheckedAccount<'info>,
+    #[account(address = config.usdt_mint @ PsmError::UnsupportedCollateral)]
+    pub collateral_mint: Account<'info, Mint>,
+    #[account(mut, constraint = user_collateral_account.mint == collateral_mint.key() @ StablecoinError::InvalidMint)]
+    pub user_collateral_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"reserve_vault", collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    #[account(mut, address = config.mint @ StablecoinError::InvalidMint)]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = user_token_account.mint == config.mint @ StablecoinError::InvalidMint,
+        constraint = user_token_account.owner == user.key() @ StablecoinError::Unauthorized
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    #[account(mut, seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: Account<'info, TokenAccount>,
+    /// CHECK: This is the PDA that owns the reserve vaults
+    #[account(seeds = [b"vault_authority"], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+// Events
+#[event]
+pub struct PsmParamsUpdatedEvent {
+    pub tin_bps: u16,
+    pub tout_bps: u16,
+    pub debt_ceiling: u64,
+}
+
+#[event]
+pub struct PsmSwapEvent {
+    pub user: Pubkey,
+    pub collateral_amount: u64,           // USDT moved, in its own base units
+    pub amount: u64,                      // Establo minted or burned for the user
+    pub fee: u64,                         // Establo fee sent to the revenue vault
+    pub swap_in: bool,                    // True for collateral -> Establo
+    pub debt: u64,                        // PSM debt after the swap
+}
+
+#[error_code(offset = 6600)]
+pub enum PsmError {
+    #[msg("PSM fee exceeds the protocol maximum")]
+    InvalidFee,
+    #[msg("Swap amount is zero after conversion")]
+    InvalidAmount,
+    #[msg("Swap would exceed the collateral's debt ceiling")]
+    DebtCeilingExceeded,
+    #[msg("Not enough collateral or PSM debt to swap out")]
+    InsufficientCollateral,
+    #[msg("Arithmetic overflow")]
+    Overflow,
+    #[msg("Only USDT is accepted by the PSM")]
+    UnsupportedCollateral,
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+// Operational roles, each granted to a wallet through its own role assignment PDA
+// The discriminant is part of the PDA seeds, so variants must never be reordered
+//
+// Governance is deliberately not a role and stays with `config.admin`: granting and revoking
+// roles, creating the admin set, the admin handover, the DAO account and its timelock, the USDT
+// oracle, rate limits, the audit registry and auditor key, and the mint/freeze authority
+// migrations. These decide who controls the protocol and its safety limits, so they are never
+// delegated to an operational key. The marketplace admin likewise keeps its handover and migrations
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    Minter,                               // Executes approved mints
+    Burner,                               // Executes approved burns
+    ReserveManager,                       // Creates the reserve vault and syncs reserves
+    Appraiser,                            // Updates property valuations
+    Liquidator,                           // Liquidates at-risk properties and sets thresholds
+    Pauser,                               // Pauses and unpauses operations
+    FeeManager,                           // Manages the fee schedule, split and exemptions
+    Compliance,                           // Freezes token accounts and manages the blacklist
+    RiskManager,                          // Sets haircuts and executes approved collateral target changes
+}
+
+// Grant a role to a wallet
+pub fn grant_role(ctx: Context<GrantRole>, role: Role, wallet: Pubkey) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.config.admin,
+        RoleError::Unauthorized
+    );
+
+    let role_assignment = &mut ctx.accounts.role_assignment;
+    role_assignment.role = role;
+    role_assignment.wallet = wallet;
+
+    // Emit event
+    emit!(RoleUpdatedEvent {
+        role,
+        wallet,
+        granted: true,
+    });
+
+    Ok(())
+}
+
+// Revoke a role; the assignment account is closed back to the admin
+pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.config.admin,
+        RoleError::Unauthorized
+    );
+
+    // Emit event
+    emit!(RoleUpdatedEvent {
+        role: ctx.accounts.role_assignment.role,
+        wallet: ctx.accounts.role_assignment.wallet,
+        granted: false,
+    });
+
+    Ok(())
+}
+
+// Account and structure definitions
+// A role assignment exists at [b"role", role, wallet] only while the role is granted
+#[account]
+pub struct RoleAssignment {
+    pub role: Role,
+    pub wallet: Pubkey,                   // Wallet holding the role
+}
+
+#[derive(Accounts)]
+#[instruction(role: Role, wallet: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, crate::Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 1 + 32,
+        seeds = [b"role", &[role as u8], wallet.as_ref()],
+        bump
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, crate::Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"role", &[role_assignment.role as u8], role_assignment.wallet.as_ref()],
+        bump
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+}
+
+// Events
+#[event]
+pub struct RoleUpdatedEvent {
+    pub role: Role,
+    pub wallet: Pubkey,
+    pub granted: bool,
+}
+
+#[error_code(offset = 6300)]
+pub enum RoleError {
+    #[msg("Unauthorized access")]
+    Unauthorized,
+}
@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 use anchor_spl::associated_token::AssociatedToken;
+use anchor_lang::Discriminator;
 use solana_program::program_pack::Pack;
 
+use crate::multisig::{self, AdminSet, PrivilegedAction, Proposal};
+use crate::roles::{Role, RoleAssignment};
+
 // Define constants for metadata as the external crate is causing issues
 pub const PREFIX: &str = "metadata";
 
@@ -16,19 +20,132 @@ pub struct Creator {
 
 declare_id!("3YGXqNveAS9ZEG1mQXGMrpRzrCGfJrLEVf2zzSS9rJwt"); // Replace with your program ID
 
+// Default loan-to-value haircuts by property class (basis points), indexed by `PropertyClass`
+// Only the value left after the haircut counts towards stablecoin backing
+pub const DEFAULT_HAIRCUT_BPS: [u16; PROPERTY_CLASS_COUNT] = [
+    2_000, // Residential
+    3_000, // Commercial
+    3_500, // Industrial
+    5_000, // Land
+    3_000, // MixedUse
+];
+
+// Account sizes, including the 8-byte discriminator
+pub const MARKETPLACE_SPACE: usize = 8 + 32 + 32 + 8 + 1 + 8 + 32 + 1
+    + 2 * PROPERTY_CLASS_COUNT + 8 * PROPERTY_CLASS_COUNT;
+pub const PROPERTY_SPACE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 128 + 256 + 1 + 1 + 1;
+// Sizes allocated by the first release, upgraded in place by `migrate_marketplace` and `migrate_property`
+pub const LEGACY_MARKETPLACE_SPACE: usize = 8 + 32 + 32 + 8 + 1;
+pub const LEGACY_PROPERTY_SPACE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 128 + 256 + 1 + 1;
+
 // Core RWA marketplace functionality for MVP
 // Initialize the marketplace
 pub fn initialize(ctx: Context<Initialize>, 
                   stablecoin_config_address: Pubkey) -> Result<()> {
+    require_keys_eq!(
+        stablecoin_config_address,
+        ctx.accounts.stablecoin_config.key(),
+        RWAMarketplaceError::InvalidStablecoinConfig
+    );
+
     let marketplace = &mut ctx.accounts.marketplace;
     marketplace.admin = ctx.accounts.admin.key();
     marketplace.stablecoin_config = stablecoin_config_address;
     marketplace.nft_count = 0;
     marketplace.liquidation_threshold = 90; // 90% (default threshold)
+    marketplace.total_property_value = 0;
+    marketplace.pending_admin = Pubkey::default();
+    marketplace.paused = 0;
+    marketplace.haircut_bps = DEFAULT_HAIRCUT_BPS;
+    marketplace.class_property_value = [0; PROPERTY_CLASS_COUNT];
+    Ok(())
+}
+
+// Upgrade a marketplace created by the first release to the current layout (marketplace admin)
+// Aggregates start empty; migrated properties count again once re-appraised
+pub fn migrate_marketplace(ctx: Context<MigrateMarketplace>) -> Result<()> {
+    let marketplace_info = ctx.accounts.marketplace.to_account_info();
+    let legacy: LegacyMarketplace = crate::read_legacy_account(
+        &marketplace_info,
+        Marketplace::discriminator(),
+        LEGACY_MARKETPLACE_SPACE,
+    )?;
+    require_keys_eq!(ctx.accounts.admin.key(), legacy.admin, RWAMarketplaceError::Unauthorized);
+
+    let marketplace = Marketplace {
+        admin: legacy.admin,
+        stablecoin_config: legacy.stablecoin_config,
+        nft_count: legacy.nft_count,
+        liquidation_threshold: legacy.liquidation_threshold,
+        total_property_value: 0,
+        pending_admin: Pubkey::default(),
+        paused: 0,
+        haircut_bps: DEFAULT_HAIRCUT_BPS,
+        class_property_value: [0; PROPERTY_CLASS_COUNT],
+    };
+    crate::write_migrated_account(
+        &marketplace_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        MARKETPLACE_SPACE,
+        &marketplace,
+    )?;
+
+    // Emit event
+    emit!(MarketplaceMigratedEvent {
+        admin: marketplace.admin,
+        nft_count: marketplace.nft_count,
+    });
+
+    Ok(())
+}
+
+// Upgrade a property listed by the first release to the current layout (marketplace admin)
+// Its value was self-declared, so it waits for an Appraiser like a new listing
+pub fn migrate_property(ctx: Context<MigrateProperty>, property_class: PropertyClass) -> Result<()> {
+    let property_info = ctx.accounts.property.to_account_info();
+    let legacy: LegacyRealEstateProperty = crate::read_legacy_account(
+        &property_info,
+        RealEstateProperty::discriminator(),
+        LEGACY_PROPERTY_SPACE,
+    )?;
+
+    let status = if legacy.status == AssetStatus::Liquidated {
+        AssetStatus::Liquidated
+    } else {
+        AssetStatus::Pending
+    };
+    let property = RealEstateProperty {
+        owner: legacy.owner,
+        mint: legacy.mint,
+        value: legacy.value,
+        initial_value: legacy.initial_value,
+        last_valuation_date: legacy.last_valuation_date,
+        location: legacy.location,
+        details: legacy.details,
+        status,
+        liquidation_threshold: legacy.liquidation_threshold,
+        class: property_class,
+    };
+    crate::write_migrated_account(
+        &property_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        PROPERTY_SPACE,
+        &property,
+    )?;
+
+    // Emit event
+    emit!(PropertyMigratedEvent {
+        mint: property.mint,
+        property_class,
+    });
+
     Ok(())
 }
 
 // List a new RWA (Real Estate) as NFT
+// The owner's asset value is only declared: the property backs the stablecoin once an Appraiser values it
 pub fn list_rwa(
     ctx: Context<ListRWA>,
     uri: String,
@@ -38,7 +155,12 @@ pub fn list_rwa(
     location: String,
     property_details: String,
     liquidation_threshold: Option<u8>,
+    property_class: PropertyClass,
 ) -> Result<()> {
+    require!(
+        ctx.accounts.marketplace.paused & crate::PAUSE_LIST_RWA == 0,
+        RWAMarketplaceError::Paused
+    );
     let marketplace = &mut ctx.accounts.marketplace;
     let property = &mut ctx.accounts.property;
     
@@ -50,8 +172,9 @@ pub fn list_rwa(
     property.last_valuation_date = Clock::get()?.unix_timestamp;
     property.location = location;
     property.details = property_details;
-    property.status = AssetStatus::Listed;
+    property.status = AssetStatus::Pending;
     property.liquidation_threshold = liquidation_threshold.unwrap_or(marketplace.liquidation_threshold);
+    property.class = property_class;
     
     // Create metadata account - simplified for compatibility
     let creators = vec![
@@ -72,15 +195,21 @@ pub fn list_rwa(
         authority: ctx.accounts.owner.to_account_info(),
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts);
     token::mint_to(cpi_ctx, 1)?;
     
+    // Let the property PDA move the NFT, so a liquidation can seize it
+    let cpi_accounts = token::Approve {
+        to: ctx.accounts.token_account.to_account_info(),
+        delegate: ctx.accounts.property.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::approve(cpi_ctx, 1)?;
+    
     // Increment NFT count
     marketplace.nft_count = marketplace.nft_count.checked_add(1).unwrap();
     
-    // Update the stablecoin contract's real estate value
-    update_stablecoin_reserves(ctx.accounts.stablecoin_config.to_account_info(), asset_value)?;
-    
     // Emit event
     emit!(RWAListedEvent {
         owner: ctx.accounts.owner.key(),
@@ -92,107 +221,64 @@ pub fn list_rwa(
     Ok(())
 }
 
-// PHASE 2 FEATURES - TO BE IMPLEMENTED
-// The following advanced features will be implemented in future phases
-
-/* 
-// Update the valuation of an RWA
-pub fn update_valuation(
-    ctx: Context<UpdateValuation>,
-    new_value: u64
-) -> Result<()> {
-    let property = &mut ctx.accounts.property;
-    let old_value = property.value;
-    
-    // Only allow admin to decrease the value (for safety)
-    if new_value < old_value && ctx.accounts.authority.key() != ctx.accounts.marketplace.admin {
-        return Err(error!(RWAMarketplaceError::Unauthorized));
-    }
-    
-    // Check if new value is below liquidation threshold
-    let liquidation_value = property.initial_value
-        .checked_mul(property.liquidation_threshold as u64)
-        .unwrap()
-        .checked_div(100)
-        .unwrap();
-        
-    if new_value < liquidation_value {
-        property.status = AssetStatus::AtRisk;
-        emit!(RWALiquidationRiskEvent {
-            mint: property.mint,
-            current_value: new_value,
-            liquidation_threshold: liquidation_value,
-        });
-    }
-    
-    // Update the property value
-    property.value = new_value;
-    property.last_valuation_date = Clock::get()?.unix_timestamp;
-    
-    // Update stablecoin reserves with the difference
-    if old_value != new_value {
-        update_stablecoin_reserves(ctx.accounts.stablecoin_config.to_account_info(), new_value)?;
-    }
+// Propose a new marketplace admin, who must accept before taking over
+// Proposing the default pubkey cancels a pending handover
+pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let marketplace = &mut ctx.accounts.marketplace;
+    marketplace.pending_admin = new_admin;
     
     // Emit event
-    emit!(RWAValuationUpdatedEvent {
-        mint: property.mint,
-        old_value,
-        new_value,
-        timestamp: property.last_valuation_date,
+    emit!(MarketplaceAdminProposedEvent {
+        current_admin: marketplace.admin,
+        pending_admin: new_admin,
     });
     
     Ok(())
 }
 
-// Transfer ownership of an RWA
-pub fn transfer_rwa(
-    ctx: Context<TransferRWA>,
-) -> Result<()> {
-    let property = &mut ctx.accounts.property;
-    
-    // Ensure the NFT is being transferred
-    let cpi_accounts = token::Transfer {
-        from: ctx.accounts.from_token_account.to_account_info(),
-        to: ctx.accounts.to_token_account.to_account_info(),
-        authority: ctx.accounts.current_owner.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer(cpi_ctx, 1)?;
-    
-    // Update property owner
-    property.owner = ctx.accounts.new_owner.key();
+// Accept a pending marketplace admin handover
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let marketplace = &mut ctx.accounts.marketplace;
+    let old_admin = marketplace.admin;
+    marketplace.admin = ctx.accounts.pending_admin.key();
+    marketplace.pending_admin = Pubkey::default();
     
     // Emit event
-    emit!(RWATransferredEvent {
-        mint: property.mint,
-        from: ctx.accounts.current_owner.key(),
-        to: ctx.accounts.new_owner.key(),
-        value: property.value,
+    emit!(MarketplaceAdminUpdatedEvent {
+        old_admin,
+        new_admin: marketplace.admin,
     });
     
     Ok(())
 }
 
-// Liquidate an RWA
+// Liquidate an at-risk RWA, gated by an approved multisig proposal and executed by a Liquidator
+// The NFT is seized to the marketplace admin and the property no longer backs the stablecoin
 pub fn liquidate_rwa(
     ctx: Context<LiquidateRWA>,
 ) -> Result<()> {
-    let property = &mut ctx.accounts.property;
+    require!(
+        ctx.accounts.marketplace.paused & crate::PAUSE_LIQUIDATION == 0,
+        RWAMarketplaceError::Paused
+    );
+    let action = PrivilegedAction::LiquidateRwa {
+        property: ctx.accounts.property.key(),
+    };
+    multisig::execute_proposal(&ctx.accounts.admin_set, &mut ctx.accounts.proposal, &action)?;
     
-    // Transfer NFT from owner to admin
+    // Transfer NFT from owner to admin, signed by the property PDA the owner approved at listing
     let cpi_accounts = token::Transfer {
         from: ctx.accounts.owner_token_account.to_account_info(),
         to: ctx.accounts.admin_token_account.to_account_info(),
-        authority: ctx.accounts.owner.to_account_info(),
+        authority: ctx.accounts.property.to_account_info(),
     };
     
     // Create a signer seeds for CPI
     let bump_seed = *ctx.bumps.get("property").unwrap();
+    let mint_key = ctx.accounts.mint.key();
     let property_seeds = &[
         b"property",
-        ctx.accounts.mint.key().as_ref(),
+        mint_key.as_ref(),
         &[bump_seed],
     ];
     let signer = &[&property_seeds[..]];
@@ -206,23 +292,126 @@ pub fn liquidate_rwa(
     );
     token::transfer(cpi_ctx, 1)?;
     
+    let property = &mut ctx.accounts.property;
+    
     // Update property status
     property.status = AssetStatus::Liquidated;
     
+    // Remove the property from the aggregate real estate backing
+    let marketplace = &mut ctx.accounts.marketplace;
+    marketplace.total_property_value = marketplace
+        .total_property_value
+        .checked_sub(property.value)
+        .ok_or(RWAMarketplaceError::Overflow)?;
+    let class_value = &mut marketplace.class_property_value[property.class as usize];
+    *class_value = class_value
+        .checked_sub(property.value)
+        .ok_or(RWAMarketplaceError::Overflow)?;
+    
     // Update real estate value in stablecoin contract
-    update_stablecoin_reserves_for_liquidation(ctx.accounts.stablecoin_config.to_account_info(), property.value)?;
+    update_stablecoin_reserves(marketplace)?;
     
     // Emit event
     emit!(RWALiquidatedEvent {
         mint: property.mint,
-        owner: ctx.accounts.owner.key(),
+        owner: property.owner,
         value: property.value,
     });
     
     Ok(())
 }
 
-// Set liquidation threshold
+// Update the valuation of an RWA (Appraiser)
+// The first appraisal replaces the declared value and adds the property to the real estate backing
+pub fn update_valuation(
+    ctx: Context<UpdateValuation>,
+    new_value: u64
+) -> Result<()> {
+    require!(
+        ctx.accounts.marketplace.paused & crate::PAUSE_VALUATION == 0,
+        RWAMarketplaceError::Paused
+    );
+    let property = &mut ctx.accounts.property;
+    let old_value = property.value;
+    let counted_value = if property.status == AssetStatus::Pending {
+        property.initial_value = new_value;
+        0
+    } else {
+        old_value
+    };
+    
+    // Check if new value is below liquidation threshold
+    let liquidation_value = property.initial_value
+        .checked_mul(property.liquidation_threshold as u64)
+        .ok_or(RWAMarketplaceError::Overflow)?
+        / 100;
+        
+    if new_value < liquidation_value {
+        property.status = AssetStatus::AtRisk;
+        emit!(RWALiquidationRiskEvent {
+            mint: property.mint,
+            current_value: new_value,
+            liquidation_threshold: liquidation_value,
+        });
+    } else if property.status != AssetStatus::Listed {
+        // First appraisal, or the valuation recovered above the threshold
+        property.status = AssetStatus::Listed;
+    }
+    
+    // Update the property value
+    property.value = new_value;
+    property.last_valuation_date = Clock::get()?.unix_timestamp;
+    
+    // Update the aggregate real estate backing and stablecoin reserves with the difference
+    if counted_value != new_value {
+        let marketplace = &mut ctx.accounts.marketplace;
+        marketplace.total_property_value = marketplace
+            .total_property_value
+            .checked_sub(counted_value)
+            .and_then(|total| total.checked_add(new_value))
+            .ok_or(RWAMarketplaceError::Overflow)?;
+        let class_value = &mut marketplace.class_property_value[property.class as usize];
+        *class_value = class_value
+            .checked_sub(counted_value)
+            .and_then(|total| total.checked_add(new_value))
+            .ok_or(RWAMarketplaceError::Overflow)?;
+        update_stablecoin_reserves(marketplace)?;
+    }
+    
+    // Emit event
+    emit!(RWAValuationUpdatedEvent {
+        mint: property.mint,
+        old_value,
+        new_value,
+        timestamp: property.last_valuation_date,
+    });
+    
+    Ok(())
+}
+
+// Pause or unpause marketplace operations (Pauser)
+// `paused` replaces the current mask; a zero mask resumes everything
+pub fn set_paused(ctx: Context<SetPaused>, paused: u8) -> Result<()> {
+    require!(
+        paused & !crate::MARKETPLACE_PAUSE_FLAGS == 0,
+        RWAMarketplaceError::InvalidPauseFlags
+    );
+    
+    let marketplace = &mut ctx.accounts.marketplace;
+    let old_paused = marketplace.paused;
+    marketplace.paused = paused;
+    
+    // Emit event
+    emit!(MarketplacePauseUpdatedEvent {
+        old_paused,
+        paused,
+        pauser: ctx.accounts.pauser.key(),
+    });
+    
+    Ok(())
+}
+
+// Set the default liquidation threshold for new RWAs (Liquidator)
 pub fn set_liquidation_threshold(
     ctx: Context<SetLiquidationThreshold>,
     threshold: u8
@@ -242,24 +431,74 @@ pub fn set_liquidation_threshold(
     
     Ok(())
 }
-*/
 
-// Helper function to update the stablecoin contract with new real estate value
-fn update_stablecoin_reserves(stablecoin_config: AccountInfo, real_estate_value: u64) -> Result<()> {
-    // In a real implementation, this would call the stablecoin contract to update reserves
-    // For now, we just emit an event
-    emit!(StablecoinReservesUpdatedEvent {
-        real_estate_value,
+// Set the loan-to-value haircut for a property class (RiskManager)
+// Applies to every property of the class at the next reserve sync
+pub fn set_haircut(
+    ctx: Context<SetHaircut>,
+    property_class: PropertyClass,
+    haircut_bps: u16
+) -> Result<()> {
+    require!(haircut_bps <= 10_000, RWAMarketplaceError::InvalidHaircut);
+    
+    let marketplace = &mut ctx.accounts.marketplace;
+    let old_haircut_bps = marketplace.haircut_bps[property_class as usize];
+    marketplace.haircut_bps[property_class as usize] = haircut_bps;
+    
+    // Emit event
+    emit!(HaircutUpdatedEvent {
+        property_class,
+        old_haircut_bps,
+        haircut_bps,
+    });
+    
+    update_stablecoin_reserves(marketplace)?;
+    
+    Ok(())
+}
+
+// PHASE 2 FEATURES - TO BE IMPLEMENTED
+// The following advanced features will be implemented in future phases
+
+/* 
+// Transfer ownership of an RWA
+pub fn transfer_rwa(
+    ctx: Context<TransferRWA>,
+) -> Result<()> {
+    let property = &mut ctx.accounts.property;
+    
+    // Ensure the NFT is being transferred
+    let cpi_accounts = token::Transfer {
+        from: ctx.accounts.from_token_account.to_account_info(),
+        to: ctx.accounts.to_token_account.to_account_info(),
+        authority: ctx.accounts.current_owner.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_ctx, 1)?;
+    
+    // Update property owner
+    property.owner = ctx.accounts.new_owner.key();
+    
+    // Emit event
+    emit!(RWATransferredEvent {
+        mint: property.mint,
+        from: ctx.accounts.current_owner.key(),
+        to: ctx.accounts.new_owner.key(),
+        value: property.value,
     });
+    
     Ok(())
 }
+*/
 
-// Helper function to update stablecoin reserves when liquidating property
-fn update_stablecoin_reserves_for_liquidation(stablecoin_config: AccountInfo, value_to_remove: u64) -> Result<()> {
-    // In a real implementation, this would call the stablecoin contract to update reserves
-    // For now, we just emit an event with 0 value to indicate liquidation
+// Helper function to update the stablecoin contract with new real estate value
+fn update_stablecoin_reserves(marketplace: &Marketplace) -> Result<()> {
+    // The stablecoin reads the marketplace aggregates in `sync_reserves`
+    // Here we just emit an event with the new aggregates
     emit!(StablecoinReservesUpdatedEvent {
-        real_estate_value: 0,
+        real_estate_value: marketplace.total_property_value,
+        haircut_real_estate_value: marketplace.haircut_property_value()?,
     });
     Ok(())
 }
@@ -271,6 +510,22 @@ pub struct Marketplace {
     pub stablecoin_config: Pubkey,        // Reference to stablecoin config
     pub nft_count: u64,                   // Number of NFTs minted
     pub liquidation_threshold: u8,        // Default liquidation threshold (percentage)
+    pub total_property_value: u64,        // Aggregate appraised value of non-liquidated properties
+    pub pending_admin: Pubkey,            // Proposed admin, default when none is pending
+    pub paused: u8,                       // Bitmask of paused operations (PAUSE_* flags)
+    pub haircut_bps: [u16; PROPERTY_CLASS_COUNT],          // Loan-to-value haircut per property class
+    pub class_property_value: [u64; PROPERTY_CLASS_COUNT], // Aggregate raw value per property class
+}
+
+impl Marketplace {
+    // Aggregate property value after each class's haircut, the part that backs the stablecoin
+    pub fn haircut_property_value(&self) -> Result<u64> {
+        let mut total: u128 = 0;
+        for (value, haircut_bps) in self.class_property_value.iter().zip(self.haircut_bps.iter()) {
+            total += *value as u128 * 10_000u128.saturating_sub(*haircut_bps as u128) / 10_000;
+        }
+        u64::try_from(total).map_err(|_| error!(RWAMarketplaceError::Overflow))
+    }
 }
 
 #[account]
@@ -284,6 +539,40 @@ pub struct RealEstateProperty {
     pub details: String,                  // Property details
     pub status: AssetStatus,              // Current status
     pub liquidation_threshold: u8,        // Asset-specific liquidation threshold
+    pub class: PropertyClass,             // Property class, selects the haircut
+}
+
+// Layouts of the first release, prefixes of the current accounts
+#[derive(AnchorDeserialize)]
+struct LegacyMarketplace {
+    admin: Pubkey,
+    stablecoin_config: Pubkey,
+    nft_count: u64,
+    liquidation_threshold: u8,
+}
+
+#[derive(AnchorDeserialize)]
+struct LegacyRealEstateProperty {
+    owner: Pubkey,
+    mint: Pubkey,
+    value: u64,
+    initial_value: u64,
+    last_valuation_date: i64,
+    location: String,
+    details: String,
+    status: AssetStatus,
+    liquidation_threshold: u8,
+}
+
+pub const PROPERTY_CLASS_COUNT: usize = 5;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PropertyClass {
+    Residential,
+    Commercial,
+    Industrial,
+    Land,
+    MixedUse,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
@@ -291,6 +580,7 @@ pub enum AssetStatus {
     Listed,
     AtRisk,
     Liquidated,
+    Pending,                              // Listed but not yet appraised, excluded from backing
 }
 
 #[derive(Accounts)]
@@ -298,17 +588,47 @@ pub struct Initialize<'info> {
     #[account(
         init,
         payer = admin,
-        space = 8 + 32 + 32 + 8 + 1,
+        space = MARKETPLACE_SPACE,
         seeds = [b"marketplace"],
         bump
     )]
     pub marketplace: Account<'info, Marketplace>,
+    #[account(
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ RWAMarketplaceError::Unauthorized
+    )]
+    pub stablecoin_config: Account<'info, crate::Config>,
     #[account(mut)]
     pub admin: Signer<'info>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[derive(Accounts)]
+pub struct MigrateMarketplace<'info> {
+    /// CHECK: Legacy marketplace, decoded and rewritten by the handler
+    #[account(mut, seeds = [b"marketplace"], bump)]
+    pub marketplace: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateProperty<'info> {
+    #[account(seeds = [b"marketplace"], bump)]
+    pub marketplace: Account<'info, Marketplace>,
+    /// CHECK: Legacy property, decoded and rewritten by the handler
+    #[account(mut, seeds = [b"property", mint.key().as_ref()], bump)]
+    pub property: UncheckedAccount<'info>,
+    /// CHECK: Only used to derive the property PDA
+    pub mint: UncheckedAccount<'info>,
+    #[account(mut, constraint = admin.key() == marketplace.admin @ RWAMarketplaceError::Unauthorized)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct ListRWA<'info> {
     #[account(mut, seeds = [b"marketplace"], bump)]
@@ -317,7 +637,7 @@ pub struct ListRWA<'info> {
     #[account(
         init,
         payer = owner,
-        space = 8 + 32 + 32 + 8 + 8 + 8 + 128 + 256 + 1 + 1,
+        space = PROPERTY_SPACE,
         seeds = [b"property", mint.key().as_ref()],
         bump
     )]
@@ -350,7 +670,8 @@ pub struct ListRWA<'info> {
     #[account(mut)]
     pub master_edition: AccountInfo<'info>,
     
-    /// CHECK: This is the stablecoin config account
+    /// CHECK: This is the stablecoin config account linked to the marketplace
+    #[account(address = marketplace.stablecoin_config @ RWAMarketplaceError::InvalidStablecoinConfig)]
     pub stablecoin_config: AccountInfo<'info>,
     
     pub token_program: Program<'info, Token>,
@@ -362,28 +683,30 @@ pub struct ListRWA<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-// These account structures are kept for future implementation
 #[derive(Accounts)]
 pub struct UpdateValuation<'info> {
     #[account(mut, seeds = [b"marketplace"], bump)]
     pub marketplace: Account<'info, Marketplace>,
     
+    // Liquidated properties no longer count towards the aggregate and can't be revalued
     #[account(
         mut,
         seeds = [b"property", property.mint.as_ref()],
         bump,
-        constraint = property.owner == owner.key() || authority.key() == marketplace.admin @ RWAMarketplaceError::Unauthorized
+        constraint = property.status != AssetStatus::Liquidated @ RWAMarketplaceError::PropertyLiquidated
     )]
     pub property: Account<'info, RealEstateProperty>,
     
-    pub owner: AccountInfo<'info>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    pub appraiser: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::Appraiser as u8], appraiser.key().as_ref()], bump)]
+    pub appraiser_role: Account<'info, RoleAssignment>,
     
-    /// CHECK: This is the stablecoin config account
+    /// CHECK: This is the stablecoin config account linked to the marketplace
+    #[account(address = marketplace.stablecoin_config @ RWAMarketplaceError::InvalidStablecoinConfig)]
     pub stablecoin_config: AccountInfo<'info>,
 }
 
+// Kept for future implementation
 #[derive(Accounts)]
 pub struct TransferRWA<'info> {
     #[account(
@@ -396,6 +719,7 @@ pub struct TransferRWA<'info> {
     
     #[account(mut)]
     pub current_owner: Signer<'info>,
+    /// CHECK: Any wallet can receive the property NFT
     pub new_owner: AccountInfo<'info>,
     
     #[account(
@@ -412,7 +736,7 @@ pub struct TransferRWA<'info> {
     )]
     pub to_token_account: Account<'info, TokenAccount>,
     
-    #[account(constraint = mint.key() == property.mint)]
+    #[account(constraint = mint.key() == property.mint @ RWAMarketplaceError::InvalidTokenAccount)]
     pub mint: Account<'info, Mint>,
     
     pub token_program: Program<'info, Token>,
@@ -434,10 +758,19 @@ pub struct LiquidateRWA<'info> {
     )]
     pub property: Account<'info, RealEstateProperty>,
     
-    #[account(mut, constraint = admin.key() == marketplace.admin @ RWAMarketplaceError::Unauthorized)]
-    pub admin: Signer<'info>,
+    #[account(seeds = [b"admin_set"], bump)]
+    pub admin_set: Account<'info, AdminSet>,
     
-    #[account(mut, constraint = owner.key() == property.owner)]
+    #[account(mut, seeds = [b"proposal", proposal.index.to_le_bytes().as_ref()], bump)]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(mut)]
+    pub liquidator: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::Liquidator as u8], liquidator.key().as_ref()], bump)]
+    pub liquidator_role: Account<'info, RoleAssignment>,
+    
+    /// CHECK: Only compared against the property owner
+    #[account(constraint = owner.key() == property.owner @ RWAMarketplaceError::Unauthorized)]
     pub owner: AccountInfo<'info>,
     
     #[account(
@@ -446,18 +779,23 @@ pub struct LiquidateRWA<'info> {
     )]
     pub owner_token_account: Account<'info, TokenAccount>,
     
+    /// CHECK: The marketplace admin receiving the seized NFT
+    #[account(address = marketplace.admin @ RWAMarketplaceError::Unauthorized)]
+    pub admin: AccountInfo<'info>,
+    
     #[account(
         init_if_needed,
-        payer = admin,
+        payer = liquidator,
         associated_token::mint = mint,
         associated_token::authority = admin,
     )]
     pub admin_token_account: Account<'info, TokenAccount>,
     
-    #[account(constraint = mint.key() == property.mint)]
+    #[account(constraint = mint.key() == property.mint @ RWAMarketplaceError::InvalidTokenAccount)]
     pub mint: Account<'info, Mint>,
     
-    /// CHECK: This is the stablecoin config account
+    /// CHECK: This is the stablecoin config account linked to the marketplace
+    #[account(address = marketplace.stablecoin_config @ RWAMarketplaceError::InvalidStablecoinConfig)]
     pub stablecoin_config: AccountInfo<'info>,
     
     pub token_program: Program<'info, Token>,
@@ -471,11 +809,69 @@ pub struct SetLiquidationThreshold<'info> {
     #[account(mut, seeds = [b"marketplace"], bump)]
     pub marketplace: Account<'info, Marketplace>,
     
-    #[account(mut, constraint = admin.key() == marketplace.admin @ RWAMarketplaceError::Unauthorized)]
+    pub liquidator: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::Liquidator as u8], liquidator.key().as_ref()], bump)]
+    pub liquidator_role: Account<'info, RoleAssignment>,
+}
+
+#[derive(Accounts)]
+pub struct SetHaircut<'info> {
+    #[account(mut, seeds = [b"marketplace"], bump)]
+    pub marketplace: Account<'info, Marketplace>,
+    
+    pub risk_manager: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::RiskManager as u8], risk_manager.key().as_ref()], bump)]
+    pub risk_manager_role: Account<'info, RoleAssignment>,
+    
+    /// CHECK: This is the stablecoin config account linked to the marketplace
+    #[account(address = marketplace.stablecoin_config @ RWAMarketplaceError::InvalidStablecoinConfig)]
+    pub stablecoin_config: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(mut, seeds = [b"marketplace"], bump)]
+    pub marketplace: Account<'info, Marketplace>,
+    
+    pub pauser: Signer<'info>,
+    #[account(seeds = [b"role", &[Role::Pauser as u8], pauser.key().as_ref()], bump)]
+    pub pauser_role: Account<'info, RoleAssignment>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(mut, seeds = [b"marketplace"], bump)]
+    pub marketplace: Account<'info, Marketplace>,
+    
+    #[account(constraint = admin.key() == marketplace.admin @ RWAMarketplaceError::Unauthorized)]
     pub admin: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut, seeds = [b"marketplace"], bump)]
+    pub marketplace: Account<'info, Marketplace>,
+    
+    #[account(
+        constraint = pending_admin.key() == marketplace.pending_admin @ RWAMarketplaceError::Unauthorized,
+        constraint = marketplace.pending_admin != Pubkey::default() @ RWAMarketplaceError::Unauthorized
+    )]
+    pub pending_admin: Signer<'info>,
+}
+
 // Events
+#[event]
+pub struct MarketplaceMigratedEvent {
+    pub admin: Pubkey,
+    pub nft_count: u64,
+}
+
+#[event]
+pub struct PropertyMigratedEvent {
+    pub mint: Pubkey,
+    pub property_class: PropertyClass,
+}
+
 #[event]
 pub struct RWAListedEvent {
     pub owner: Pubkey,
@@ -519,12 +915,39 @@ pub struct LiquidationThresholdUpdatedEvent {
     pub new_threshold: u8,
 }
 
+#[event]
+pub struct HaircutUpdatedEvent {
+    pub property_class: PropertyClass,
+    pub old_haircut_bps: u16,
+    pub haircut_bps: u16,
+}
+
+#[event]
+pub struct MarketplacePauseUpdatedEvent {
+    pub old_paused: u8,
+    pub paused: u8,
+    pub pauser: Pubkey,
+}
+
+#[event]
+pub struct MarketplaceAdminProposedEvent {
+    pub current_admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct MarketplaceAdminUpdatedEvent {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
 #[event]
 pub struct StablecoinReservesUpdatedEvent {
     pub real_estate_value: u64,
+    pub haircut_real_estate_value: u64,   // Value counted towards backing after class haircuts
 }
 
-#[error_code]
+#[error_code(offset = 6100)]
 pub enum RWAMarketplaceError {
     #[msg("Unauthorized access")]
     Unauthorized,
@@ -534,4 +957,16 @@ pub enum RWAMarketplaceError {
     NotEligibleForLiquidation,
     #[msg("Invalid liquidation threshold (must be between 1-100)")]
     InvalidThreshold,
+    #[msg("Stablecoin config does not match the marketplace")]
+    InvalidStablecoinConfig,
+    #[msg("Arithmetic overflow")]
+    Overflow,
+    #[msg("Property has been liquidated")]
+    PropertyLiquidated,
+    #[msg("Operation is paused")]
+    Paused,
+    #[msg("Unknown pause flags")]
+    InvalidPauseFlags,
+    #[msg("Haircut must be at most 10,000 bps")]
+    InvalidHaircut,
 }
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::Pubkey;
+use green_stablecoin::audit::{audit_leaf, audit_message, audit_node, verify_merkle_proof};
+
+// Build every level of a Merkle tree, leaves first; an odd node is carried up unchanged
+fn build_tree(leaves: Vec<[u8; 32]>) -> Vec<Vec<[u8; 32]>> {
+    let mut levels = vec![leaves];
+    while levels.last().unwrap().len() > 1 {
+        let next = levels
+            .last()
+            .unwrap()
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => audit_node(left, right),
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+        levels.push(next);
+    }
+    levels
+}
+
+// Sibling hashes from the leaf at `index` up to the root
+fn proof_for(levels: &[Vec<[u8; 32]>], mut index: usize) -> Vec<[u8; 32]> {
+    let mut proof = Vec::new();
+    for level in &levels[..levels.len() - 1] {
+        let sibling = index ^ 1;
+        if sibling < level.len() {
+            proof.push(level[sibling]);
+        }
+        index /= 2;
+    }
+    proof
+}
+
+fn audited_properties() -> Vec<(Pubkey, u64)> {
+    (1..=5u64)
+        .map(|i| (Pubkey::new_unique(), i * 250_000_000_000))
+        .collect()
+}
+
+#[test]
+fn test_every_audited_property_proves_against_the_root() {
+    let properties = audited_properties();
+    let leaves = properties
+        .iter()
+        .map(|(mint, value)| audit_leaf(mint, *value))
+        .collect();
+    let levels = build_tree(leaves);
+    let root = levels.last().unwrap()[0];
+
+    for (index, (mint, value)) in properties.iter().enumerate() {
+        let proof = proof_for(&levels, index);
+        assert!(verify_merkle_proof(&proof, &root, audit_leaf(mint, *value)));
+    }
+}
+
+#[test]
+fn test_inflated_value_does_not_prove() {
+    let properties = audited_properties();
+    let leaves = properties
+        .iter()
+        .map(|(mint, value)| audit_leaf(mint, *value))
+        .collect();
+    let levels = build_tree(leaves);
+    let root = levels.last().unwrap()[0];
+
+    // The property's on-chain value was raised above what the auditor signed off on
+    let (mint, value) = properties[2];
+    let proof = proof_for(&levels, 2);
+    assert!(!verify_merkle_proof(&proof, &root, audit_leaf(&mint, value + 1)));
+}
+
+#[test]
+fn test_audit_message_binds_root_and_epoch() {
+    let root = [7u8; 32];
+    assert_ne!(audit_message(&root, 1), audit_message(&root, 2));
+    assert_ne!(audit_message(&root, 1), audit_message(&[8u8; 32], 1));
+    assert_eq!(audit_message(&root, 1).len(), b"establo-audit".len() + 32 + 8);
+}
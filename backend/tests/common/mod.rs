@@ -0,0 +1,495 @@
+// Fixtures shared by the program tests; not every test file uses every helper
+#![allow(dead_code)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::ToAccountMetas;
+use anchor_spl::token::{self, spl_token};
+use green_stablecoin::multisig::{AdminSet, Proposal};
+use green_stablecoin::oracle;
+use green_stablecoin::roles::RoleAssignment;
+use green_stablecoin::rwa_marketplace::{self, Marketplace, PROPERTY_CLASS_COUNT};
+use green_stablecoin::{self, Attestation, Config, FeeSplit, PrivilegedAction, ReserveStatus, Role};
+use solana_program::program_option::COption;
+use solana_program::program_pack::Pack;
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    hash::Hash,
+    instruction::{Instruction, InstructionError},
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+// Serialize an Anchor account into a program-owned test account
+pub fn program_account<T: AccountSerialize>(account: &T) -> Account {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: green_stablecoin::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn mint_account(mint_authority: Pubkey) -> Account {
+    let mut data = vec![0u8; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        mint_authority: COption::Some(mint_authority),
+        supply: 1_000_000,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: spl_token::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> Account {
+    let mut data = vec![0u8; spl_token::state::Account::LEN];
+    spl_token::state::Account {
+        mint,
+        owner,
+        amount,
+        state: spl_token::state::AccountState::Initialized,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: spl_token::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+// A token account that has approved `delegate` to move `delegated_amount` of its balance
+pub fn delegated_token_account(
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+    delegate: Pubkey,
+    delegated_amount: u64,
+) -> Account {
+    let mut account = token_account(mint, owner, amount);
+    spl_token::state::Account {
+        mint,
+        owner,
+        amount,
+        delegate: COption::Some(delegate),
+        state: spl_token::state::AccountState::Initialized,
+        delegated_amount,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut account.data);
+    account
+}
+
+// An executed-ready proposal approved by `approver`
+pub fn proposal_account(index: u64, action: PrivilegedAction, approver: Pubkey) -> (Pubkey, Account) {
+    let (proposal, _) = Pubkey::find_program_address(
+        &[b"proposal", index.to_le_bytes().as_ref()],
+        &green_stablecoin::id(),
+    );
+    let account = program_account(&Proposal {
+        index,
+        proposer: approver,
+        action,
+        approvals: vec![approver],
+        executed: false,
+        created_at: 0,
+        expires_at: i64::MAX,
+    });
+    (proposal, account)
+}
+
+// A granted role assignment PDA for `wallet`
+pub fn role_account(role: Role, wallet: Pubkey) -> (Pubkey, Account) {
+    let (role_assignment, _) = Pubkey::find_program_address(
+        &[b"role", &[role as u8], wallet.as_ref()],
+        &green_stablecoin::id(),
+    );
+    (role_assignment, program_account(&RoleAssignment { role, wallet }))
+}
+
+// The fixture's marketplace PDA with the default haircuts and the given raw value per property class
+pub fn marketplace_account(
+    fixture: &Fixture,
+    class_property_value: [u64; PROPERTY_CLASS_COUNT],
+) -> (Pubkey, Account) {
+    let (marketplace, _) = Pubkey::find_program_address(&[b"marketplace"], &green_stablecoin::id());
+    let account = program_account(&Marketplace {
+        admin: fixture.admin.pubkey(),
+        stablecoin_config: fixture.config,
+        nft_count: 0,
+        liquidation_threshold: 90,
+        total_property_value: class_property_value.iter().sum(),
+        pending_admin: Pubkey::default(),
+        paused: 0,
+        haircut_bps: rwa_marketplace::DEFAULT_HAIRCUT_BPS,
+        class_property_value,
+    });
+    (marketplace, account)
+}
+
+// The blacklist PDA address for `wallet`, whether or not it has been created
+pub fn blacklist_address(wallet: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"blacklist", wallet.as_ref()], &green_stablecoin::id()).0
+}
+
+// A level-1 KYC attestation PDA for `wallet`, valid until `expiry`
+pub fn attestation_account(wallet: Pubkey, expiry: i64) -> (Pubkey, Account) {
+    let (attestation, _) = Pubkey::find_program_address(
+        &[b"attestation", wallet.as_ref()],
+        &green_stablecoin::id(),
+    );
+    let account = program_account(&Attestation {
+        wallet,
+        level: 1,
+        expiry,
+        jurisdiction: *b"CH",
+        issuer: Pubkey::new_unique(),
+    });
+    (attestation, account)
+}
+
+// A Pyth-style USDT/USD price account published now
+pub fn price_account(price: i64, expo: i32) -> Account {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+    price_account_at(price, expo, now)
+}
+
+// A Pyth-style USDT/USD price account published at `publish_time`
+pub fn price_account_at(price: i64, expo: i32, publish_time: i64) -> Account {
+    let mut data = vec![0u8; oracle::PYTH_PRICE_ACCOUNT_MIN_LEN];
+    let mut write = |offset: usize, bytes: &[u8]| {
+        data[offset..offset + bytes.len()].copy_from_slice(bytes)
+    };
+    write(oracle::PYTH_MAGIC_OFFSET, &oracle::PYTH_MAGIC.to_le_bytes());
+    write(
+        oracle::PYTH_ACCOUNT_TYPE_OFFSET,
+        &oracle::PYTH_PRICE_ACCOUNT_TYPE.to_le_bytes(),
+    );
+    write(oracle::PYTH_EXPO_OFFSET, &expo.to_le_bytes());
+    write(oracle::PYTH_TIMESTAMP_OFFSET, &publish_time.to_le_bytes());
+    write(oracle::PYTH_AGG_PRICE_OFFSET, &price.to_le_bytes());
+    write(
+        oracle::PYTH_AGG_STATUS_OFFSET,
+        &oracle::PYTH_STATUS_TRADING.to_le_bytes(),
+    );
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: oracle::PYTH_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+// Read `get_reserves` the way clients do, from the return data of a simulated call
+pub async fn reserve_status(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: Hash,
+    fixture: &Fixture,
+) -> ReserveStatus {
+    let program_id = green_stablecoin::id();
+    let ix = Instruction {
+        program_id,
+        accounts: green_stablecoin::accounts::GetReserves {
+            config: fixture.config,
+            usdt_mint: fixture.usdt_mint,
+            usdt_vault: fixture.usdt_vault,
+        }
+        .to_account_metas(None),
+        data: anchor_lang::InstructionData::data(&green_stablecoin::instruction::GetReserves {}),
+    };
+    let transaction =
+        Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[payer], recent_blockhash);
+    let simulation = banks_client.simulate_transaction(transaction).await.unwrap();
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, program_id);
+    // The runtime trims trailing zero bytes from return data
+    let mut data = return_data.data;
+    data.resize(std::mem::size_of::<ReserveStatus>(), 0);
+    ReserveStatus::deserialize(&mut data.as_slice()).unwrap()
+}
+
+pub fn assert_custom_error(result: std::result::Result<(), BanksClientError>, code: u32) {
+    match result.unwrap_err().unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(actual)) => {
+            assert_eq!(actual, code)
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+// Sign `ix` with the payer and `signers`, then process it
+pub async fn process(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: Hash,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> std::result::Result<(), BanksClientError> {
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+    tx.sign(&all_signers, recent_blockhash);
+    banks_client.process_transaction(tx).await
+}
+
+// Read and deserialize a program account
+pub async fn program_state<T: AccountDeserialize>(banks_client: &mut BanksClient, address: Pubkey) -> T {
+    let account = banks_client.get_account(address).await.unwrap().unwrap();
+    T::try_deserialize(&mut account.data.as_ref()).unwrap()
+}
+
+pub async fn token_balance(banks_client: &mut BanksClient, address: Pubkey) -> u64 {
+    let account = banks_client.get_account(address).await.unwrap().unwrap();
+    spl_token::state::Account::unpack(&account.data).unwrap().amount
+}
+
+pub struct Fixture {
+    pub admin: Keypair,
+    pub sender: Keypair,
+    pub recipient: Pubkey,
+    pub config: Pubkey,
+    pub admin_set: Pubkey,
+    pub minter_role: Pubkey,
+    pub burner_role: Pubkey,
+    pub fee_split: Pubkey,
+    pub mint: Pubkey,
+    pub mint_authority: Pubkey,
+    pub vault_authority: Pubkey,
+    pub usdt_mint: Pubkey,
+    pub usdt_vault: Pubkey,
+    pub revenue_vault: Pubkey,
+    pub usdt_oracle: Pubkey,
+    pub sender_token_account: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub dao_token_account: Pubkey,
+    pub attacker_token_account: Pubkey,
+}
+
+// The fixture's config: 1,000,000 Establo outstanding, fully backed, with a 0.5% transfer fee
+// Tests override single fields with `Config { field, ..base_config(&fixture) }`
+pub fn base_config(fixture: &Fixture) -> Config {
+    Config {
+        admin: fixture.admin.pubkey(),
+        usdt_mint: fixture.usdt_mint,
+        dao_token_account: fixture.dao_token_account,
+        mint: fixture.mint,
+        decimals: 6,
+        usdt_reserve: 1_000_000,
+        real_estate_value: 1_000_000,
+        haircut_real_estate_value: 1_000_000,
+        total_supply: 1_000_000,
+        fee_bps: 50,
+        dao_timelock: green_stablecoin::DEFAULT_DAO_TIMELOCK,
+        rate_limit_window: green_stablecoin::DEFAULT_RATE_LIMIT_WINDOW,
+        usdt_oracle: fixture.usdt_oracle,
+        min_usdt_price_bps: green_stablecoin::DEFAULT_MIN_USDT_PRICE_BPS,
+        max_oracle_staleness: 3_600,
+        target_usdt_bps: green_stablecoin::DEFAULT_TARGET_USDT_BPS,
+        target_re_bps: green_stablecoin::DEFAULT_TARGET_RE_BPS,
+        ramp_start_usdt_bps: green_stablecoin::DEFAULT_TARGET_USDT_BPS,
+        ramp_start_re_bps: green_stablecoin::DEFAULT_TARGET_RE_BPS,
+        min_usdt_bps: green_stablecoin::DEFAULT_MIN_USDT_BPS,
+        max_usdt_bps: green_stablecoin::DEFAULT_MAX_USDT_BPS,
+        min_re_bps: green_stablecoin::DEFAULT_MIN_RE_BPS,
+        max_re_bps: green_stablecoin::DEFAULT_MAX_RE_BPS,
+        ..Config::default()
+    }
+}
+
+// A stablecoin deployment with a funded sender, a recipient, the DAO, an attacker
+// and a USDT reserve vault holding the full USDT reserve
+pub fn setup() -> (ProgramTest, Fixture) {
+    let program_id = green_stablecoin::id();
+    let mut program_test = ProgramTest::new(
+        "green_stablecoin",
+        program_id,
+        processor!(green_stablecoin::entry),
+    );
+
+    let admin = Keypair::new();
+    let sender = Keypair::new();
+    let (config, _) = Pubkey::find_program_address(&[b"config"], &program_id);
+    let (admin_set, _) = Pubkey::find_program_address(&[b"admin_set"], &program_id);
+    let (fee_split, _) = Pubkey::find_program_address(&[b"fee_split"], &program_id);
+    let (mint_authority, _) = Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+    let (vault_authority, _) = Pubkey::find_program_address(&[b"vault_authority"], &program_id);
+    let (revenue_vault, _) = Pubkey::find_program_address(&[b"revenue_vault"], &program_id);
+    let mint = Pubkey::new_unique();
+    let usdt_mint = Pubkey::new_unique();
+    let (usdt_vault, _) =
+        Pubkey::find_program_address(&[b"reserve_vault", usdt_mint.as_ref()], &program_id);
+    let usdt_oracle = Pubkey::new_unique();
+    let sender_token_account = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let recipient_token_account = Pubkey::new_unique();
+    let dao_token_account = Pubkey::new_unique();
+    let attacker_token_account = Pubkey::new_unique();
+    // The admin doubles as the minter and burner; no fee manager is granted
+    let (minter_role, minter_role_data) = role_account(Role::Minter, admin.pubkey());
+    let (burner_role, burner_role_data) = role_account(Role::Burner, admin.pubkey());
+
+    let fixture = Fixture {
+        admin,
+        sender,
+        recipient,
+        config,
+        admin_set,
+        minter_role,
+        burner_role,
+        fee_split,
+        mint,
+        mint_authority,
+        vault_authority,
+        usdt_mint,
+        usdt_vault,
+        revenue_vault,
+        usdt_oracle,
+        sender_token_account,
+        recipient_token_account,
+        dao_token_account,
+        attacker_token_account,
+    };
+
+    program_test.add_account(config, program_account(&base_config(&fixture)));
+    program_test.add_account(usdt_oracle, price_account(100_000_000, -8));
+    program_test.add_account(
+        admin_set,
+        program_account(&AdminSet {
+            signers: vec![fixture.admin.pubkey()],
+            threshold: 1,
+            proposal_count: 2,
+        }),
+    );
+    program_test.add_account(minter_role, minter_role_data);
+    program_test.add_account(burner_role, burner_role_data);
+    program_test.add_account(fee_split, program_account(&FeeSplit { beneficiaries: vec![] }));
+    program_test.add_account(mint, mint_account(mint_authority));
+    program_test.add_account(usdt_mint, mint_account(Pubkey::new_unique()));
+    program_test.add_account(usdt_vault, token_account(usdt_mint, vault_authority, 1_000_000));
+    program_test.add_account(revenue_vault, token_account(mint, vault_authority, 0));
+    program_test.add_account(
+        sender_token_account,
+        token_account(mint, fixture.sender.pubkey(), 1_000_000),
+    );
+    program_test.add_account(recipient_token_account, token_account(mint, recipient, 0));
+    program_test.add_account(dao_token_account, token_account(mint, Pubkey::new_unique(), 0));
+    program_test.add_account(attacker_token_account, token_account(mint, Pubkey::new_unique(), 0));
+
+    (program_test, fixture)
+}
+
+pub fn transfer_accounts(fixture: &Fixture) -> green_stablecoin::accounts::StablecoinTransfer {
+    let program_id = green_stablecoin::id();
+    green_stablecoin::accounts::StablecoinTransfer {
+        config: fixture.config,
+        sender: fixture.sender.pubkey(),
+        sender_token_account: fixture.sender_token_account,
+        recipient_token_account: fixture.recipient_token_account,
+        dao_token_account: fixture.dao_token_account,
+        fee_split: fixture.fee_split,
+        sender_fee_exemption: Pubkey::find_program_address(
+            &[b"fee_exemption", fixture.sender.pubkey().as_ref()],
+            &program_id,
+        )
+        .0,
+        recipient_fee_exemption: Pubkey::find_program_address(
+            &[b"fee_exemption", fixture.recipient.as_ref()],
+            &program_id,
+        )
+        .0,
+        sender_blacklist: Pubkey::find_program_address(
+            &[b"blacklist", fixture.sender.pubkey().as_ref()],
+            &program_id,
+        )
+        .0,
+        recipient_blacklist: Pubkey::find_program_address(
+            &[b"blacklist", fixture.recipient.as_ref()],
+            &program_id,
+        )
+        .0,
+        token_program: token::ID,
+    }
+}
+
+// Mint accounts for the fixture's minter, executing `proposal`
+pub fn mint_accounts(fixture: &Fixture, proposal: Pubkey) -> Vec<AccountMeta> {
+    green_stablecoin::accounts::MintTokens {
+        config: fixture.config,
+        admin_set: fixture.admin_set,
+        proposal,
+        minter: fixture.admin.pubkey(),
+        minter_role: fixture.minter_role,
+        mint: fixture.mint,
+        recipient_token_account: fixture.recipient_token_account,
+        recipient_blacklist: blacklist_address(fixture.recipient),
+        mint_authority: fixture.mint_authority,
+        usdt_oracle: fixture.usdt_oracle,
+        token_program: token::ID,
+    }
+    .to_account_metas(None)
+}
+
+// Deposit accounts for the fixture's sender, paying USDT from `user_usdt_account`
+pub fn deposit_accounts(
+    fixture: &Fixture,
+    attestation: Pubkey,
+    user_usdt_account: Pubkey,
+) -> Vec<AccountMeta> {
+    green_stablecoin::accounts::DepositAndMint {
+        config: fixture.config,
+        user: fixture.sender.pubkey(),
+        attestation,
+        user_blacklist: blacklist_address(fixture.sender.pubkey()),
+        usdt_mint: fixture.usdt_mint,
+        user_usdt_account,
+        usdt_vault: fixture.usdt_vault,
+        mint: fixture.mint,
+        user_token_account: fixture.sender_token_account,
+        mint_authority: fixture.mint_authority,
+        usdt_oracle: fixture.usdt_oracle,
+        revenue_vault: fixture.revenue_vault,
+        token_program: token::ID,
+    }
+    .to_account_metas(None)
+}
+
+// Redeem accounts for the fixture's sender, paying USDT into `holder_usdt_account`
+pub fn redeem_accounts(
+    fixture: &Fixture,
+    attestation: Pubkey,
+    holder_usdt_account: Pubkey,
+) -> Vec<AccountMeta> {
+    green_stablecoin::accounts::Redeem {
+        config: fixture.config,
+        holder: fixture.sender.pubkey(),
+        attestation,
+        holder_blacklist: blacklist_address(fixture.sender.pubkey()),
+        mint: fixture.mint,
+        holder_token_account: fixture.sender_token_account,
+        usdt_mint: fixture.usdt_mint,
+        usdt_vault: fixture.usdt_vault,
+        holder_usdt_account,
+        vault_authority: fixture.vault_authority,
+        revenue_vault: fixture.revenue_vault,
+        token_program: token::ID,
+    }
+    .to_account_metas(None)
+}
@@ -0,0 +1,69 @@
+use green_stablecoin::{transfer_fee, Config, FeeTier};
+
+fn fee_config(fee_bps: u16, min_fee: u64, fee_tiers: Vec<FeeTier>) -> Config {
+    Config {
+        fee_bps,
+        min_fee,
+        fee_tiers,
+        ..Config::default()
+    }
+}
+
+#[test]
+fn test_base_rate_applies_without_tiers() {
+    let config = fee_config(50, 0, vec![]);
+
+    assert_eq!(transfer_fee(&config, 1_000_000), 5_000);
+}
+
+#[test]
+fn test_largest_reached_tier_sets_the_rate() {
+    let config = fee_config(
+        50,
+        0,
+        vec![
+            FeeTier { min_amount: 10_000, fee_bps: 30 },
+            FeeTier { min_amount: 1_000_000, fee_bps: 10 },
+        ],
+    );
+
+    // Below the first bracket the base rate applies
+    assert_eq!(transfer_fee(&config, 9_999), 49);
+    // Exactly at a bracket boundary that bracket applies
+    assert_eq!(transfer_fee(&config, 10_000), 30);
+    assert_eq!(transfer_fee(&config, 999_999), 2_999);
+    assert_eq!(transfer_fee(&config, 1_000_000), 1_000);
+}
+
+#[test]
+fn test_fee_rounds_down() {
+    let config = fee_config(50, 0, vec![]);
+
+    // 199 * 0.5% = 0.995, truncated to zero
+    assert_eq!(transfer_fee(&config, 199), 0);
+    assert_eq!(transfer_fee(&config, 200), 1);
+}
+
+#[test]
+fn test_min_fee_is_a_floor() {
+    let config = fee_config(50, 100, vec![]);
+
+    assert_eq!(transfer_fee(&config, 1_000), 100);
+    assert_eq!(transfer_fee(&config, 1_000_000), 5_000);
+}
+
+#[test]
+fn test_min_fee_never_exceeds_amount() {
+    let config = fee_config(50, 100, vec![]);
+
+    assert_eq!(transfer_fee(&config, 40), 40);
+    assert_eq!(transfer_fee(&config, 0), 0);
+}
+
+#[test]
+fn test_large_amount_does_not_overflow() {
+    let config = fee_config(green_stablecoin::MAX_FEE_BPS, 0, vec![]);
+
+    // 5% of the largest amount, computed in u128 before narrowing
+    assert_eq!(transfer_fee(&config, u64::MAX), u64::MAX / 20);
+}
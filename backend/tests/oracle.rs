@@ -0,0 +1,67 @@
+use green_stablecoin::oracle::{self, parse_price_account, OraclePrice};
+
+// Local mock of a Pyth price account holding a single aggregate price
+fn mock_price_account(price: i64, conf: u64, expo: i32, publish_time: i64, status: u32) -> Vec<u8> {
+    let mut data = vec![0u8; oracle::PYTH_PRICE_ACCOUNT_MIN_LEN];
+    let mut write = |offset: usize, bytes: &[u8]| {
+        data[offset..offset + bytes.len()].copy_from_slice(bytes)
+    };
+    write(oracle::PYTH_MAGIC_OFFSET, &oracle::PYTH_MAGIC.to_le_bytes());
+    write(
+        oracle::PYTH_ACCOUNT_TYPE_OFFSET,
+        &oracle::PYTH_PRICE_ACCOUNT_TYPE.to_le_bytes(),
+    );
+    write(oracle::PYTH_EXPO_OFFSET, &expo.to_le_bytes());
+    write(oracle::PYTH_TIMESTAMP_OFFSET, &publish_time.to_le_bytes());
+    write(oracle::PYTH_AGG_PRICE_OFFSET, &price.to_le_bytes());
+    write(oracle::PYTH_AGG_CONF_OFFSET, &conf.to_le_bytes());
+    write(oracle::PYTH_AGG_STATUS_OFFSET, &status.to_le_bytes());
+    data
+}
+
+#[test]
+fn test_parse_trading_price() {
+    let data = mock_price_account(99_950_000, 20_000, -8, 1_700_000_000, oracle::PYTH_STATUS_TRADING);
+
+    let price = parse_price_account(&data).unwrap();
+    assert_eq!(
+        price,
+        OraclePrice {
+            price: 99_950_000,
+            conf: 20_000,
+            expo: -8,
+            publish_time: 1_700_000_000,
+        }
+    );
+    assert_eq!(price.price_bps(), 9_995);
+}
+
+#[test]
+fn test_price_bps_handles_exponents_and_negative_prices() {
+    let price = |price, expo| OraclePrice {
+        price,
+        conf: 0,
+        expo,
+        publish_time: 0,
+    };
+    assert_eq!(price(1, 0).price_bps(), 10_000);
+    assert_eq!(price(97_000, -5).price_bps(), 9_700);
+    assert_eq!(price(-1, -8).price_bps(), 0);
+}
+
+#[test]
+fn test_rejects_halted_feed() {
+    // Status 2 is halted
+    let data = mock_price_account(100_000_000, 0, -8, 1_700_000_000, 2);
+    assert!(parse_price_account(&data).is_err());
+}
+
+#[test]
+fn test_rejects_non_price_account() {
+    let mut data = mock_price_account(100_000_000, 0, -8, 1_700_000_000, oracle::PYTH_STATUS_TRADING);
+    data[oracle::PYTH_MAGIC_OFFSET] ^= 0xff;
+    assert!(parse_price_account(&data).is_err());
+
+    // Truncated accounts are rejected rather than read out of bounds
+    assert!(parse_price_account(&[0u8; 64]).is_err());
+}
@@ -0,0 +1,80 @@
+use green_stablecoin::proof_of_reserves::{
+    snapshot_hash, verify_snapshot_chain, ChainIssue, ReserveSnapshot,
+};
+
+// Build a valid chain of `len` snapshots, as `snapshot_reserves` would record them
+fn build_chain(len: u64) -> Vec<ReserveSnapshot> {
+    let mut chain: Vec<ReserveSnapshot> = Vec::new();
+    for index in 0..len {
+        let mut snapshot = ReserveSnapshot {
+            index,
+            slot: 1_000 + index * 100,
+            timestamp: 1_700_000_000 + index as i64 * 3_600,
+            total_supply: 1_000_000 + index * 10_000,
+            usdt_vault_balance: 700_000 + index * 7_000,
+            real_estate_value: 300_000 + index * 3_000,
+            prev_hash: chain.last().map(|previous| previous.hash).unwrap_or([0u8; 32]),
+            hash: [0u8; 32],
+        };
+        snapshot.hash = snapshot_hash(&snapshot);
+        chain.push(snapshot);
+    }
+    chain
+}
+
+#[test]
+fn test_valid_chain_has_no_issues() {
+    let chain = build_chain(5);
+    assert!(verify_snapshot_chain(&chain).is_empty());
+
+    // A chain may also be verified from any starting point
+    assert!(verify_snapshot_chain(&chain[2..]).is_empty());
+}
+
+#[test]
+fn test_tampered_snapshot_is_flagged() {
+    let mut chain = build_chain(5);
+
+    // Inflate the recorded USDT balance without recomputing the hash
+    chain[2].usdt_vault_balance += 1;
+    assert_eq!(
+        verify_snapshot_chain(&chain),
+        vec![ChainIssue::HashMismatch { index: 2 }]
+    );
+
+    // Recomputing the hash hides the edit in that snapshot but breaks the next link
+    chain[2].hash = snapshot_hash(&chain[2]);
+    assert_eq!(
+        verify_snapshot_chain(&chain),
+        vec![ChainIssue::BrokenLink { index: 3 }]
+    );
+}
+
+#[test]
+fn test_missing_snapshot_is_flagged() {
+    let mut chain = build_chain(5);
+    chain.remove(3);
+
+    assert_eq!(
+        verify_snapshot_chain(&chain),
+        vec![
+            ChainIssue::Gap {
+                expected: 3,
+                found: 4
+            },
+            ChainIssue::BrokenLink { index: 4 },
+        ]
+    );
+}
+
+#[test]
+fn test_genesis_must_have_zeroed_previous_hash() {
+    let mut chain = build_chain(1);
+    chain[0].prev_hash = [1u8; 32];
+    chain[0].hash = snapshot_hash(&chain[0]);
+
+    assert_eq!(
+        verify_snapshot_chain(&chain),
+        vec![ChainIssue::BrokenLink { index: 0 }]
+    );
+}
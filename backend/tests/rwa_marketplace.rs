@@ -1,9 +1,17 @@
+mod common;
+
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 use anchor_spl::associated_token::AssociatedToken;
-use green_stablecoin::rwa_marketplace::{self, Marketplace, RealEstateProperty, AssetStatus};
+use common::{
+    assert_custom_error, delegated_token_account, marketplace_account, mint_account, process,
+    program_account, program_state, proposal_account, role_account, setup, token_balance,
+};
+use green_stablecoin::rwa_marketplace::{self, Marketplace, RealEstateProperty, AssetStatus, PropertyClass};
 use green_stablecoin::Config as StablecoinConfig;
 use green_stablecoin::program::GreenStablecoin;
+use green_stablecoin::{PrivilegedAction, Role};
 use solana_program::system_program;
 use solana_program_test::*;
 use solana_sdk::{
@@ -13,10 +21,42 @@ use solana_sdk::{
     transport::TransportError,
 };
 
+// A stablecoin config at its PDA, administered by `admin`
+fn config_account(admin: Pubkey) -> Account {
+    program_account(&StablecoinConfig {
+        admin,
+        ..StablecoinConfig::default()
+    })
+}
+
+fn initialize_marketplace_ix(
+    admin: Pubkey,
+    stablecoin_config_address: Pubkey,
+) -> anchor_lang::solana_program::instruction::Instruction {
+    let program_id = green_stablecoin::id();
+    let (marketplace_pda, _) = Pubkey::find_program_address(&[b"marketplace"], &program_id);
+    let (config_pda, _) = Pubkey::find_program_address(&[b"config"], &program_id);
+    anchor_lang::solana_program::instruction::Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(marketplace_pda, false),
+            AccountMeta::new_readonly(config_pda, false),
+            AccountMeta::new(admin, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data: anchor_lang::InstructionData::data(
+            &green_stablecoin::instruction::InitializeMarketplace {
+                stablecoin_config_address,
+            },
+        ),
+    }
+}
+
 #[tokio::test]
 async fn test_rwa_marketplace_initialize() {
     // Set up program test
-    let program_id = Pubkey::new_unique();
+    let program_id = green_stablecoin::id();
     let mut program_test = ProgramTest::new(
         "green_stablecoin",
         program_id,
@@ -26,9 +66,9 @@ async fn test_rwa_marketplace_initialize() {
     // Generate necessary keypairs
     let admin = Keypair::new();
     
-    // Create stablecoin config (simulated)
-    let stablecoin_config_keypair = Keypair::new();
-    let stablecoin_config = stablecoin_config_keypair.pubkey();
+    // Create stablecoin config at its PDA
+    let (stablecoin_config, _) = Pubkey::find_program_address(&[b"config"], &program_id);
+    program_test.add_account(stablecoin_config, config_account(admin.pubkey()));
 
     // Add accounts to test environment
     program_test.add_account(
@@ -49,22 +89,7 @@ async fn test_rwa_marketplace_initialize() {
     let (marketplace_pda, bump) = Pubkey::find_program_address(&[b"marketplace"], &program_id);
 
     // Create initialize instruction
-    let ix = anchor_lang::solana_program::instruction::Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new(marketplace_pda, false),
-            AccountMeta::new(admin.pubkey(), true),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-        ],
-        data: anchor_lang::InstructionData::new(
-            anchor_lang::Discriminator::new("global", "initialize_marketplace"),
-            rwa_marketplace::instruction::Initialize {
-                stablecoin_config_address: stablecoin_config,
-            },
-        )
-        .to_vec(),
-    };
+    let ix = initialize_marketplace_ix(admin.pubkey(), stablecoin_config);
 
     // Create and sign transaction
     let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
@@ -82,14 +107,498 @@ async fn test_rwa_marketplace_initialize() {
     assert_eq!(marketplace.liquidation_threshold, 90);
 }
 
+#[tokio::test]
+async fn test_initialize_marketplace_rejects_foreign_config_address() {
+    let program_id = green_stablecoin::id();
+    let mut program_test = ProgramTest::new(
+        "green_stablecoin",
+        program_id,
+        processor!(green_stablecoin::entry),
+    );
+
+    let admin = Keypair::new();
+    let (stablecoin_config, _) = Pubkey::find_program_address(&[b"config"], &program_id);
+    program_test.add_account(stablecoin_config, config_account(admin.pubkey()));
+    program_test.add_account(
+        admin.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    // Point the marketplace at a config the program does not control
+    let ix = initialize_marketplace_ix(admin.pubkey(), Pubkey::new_unique());
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &admin], recent_blockhash);
+    let result = banks_client.process_transaction(tx).await;
+
+    assert_custom_error(
+        result,
+        rwa_marketplace::RWAMarketplaceError::InvalidStablecoinConfig.into(),
+    );
+}
+
+#[tokio::test]
+async fn test_initialize_marketplace_rejects_non_admin() {
+    let program_id = green_stablecoin::id();
+    let mut program_test = ProgramTest::new(
+        "green_stablecoin",
+        program_id,
+        processor!(green_stablecoin::entry),
+    );
+
+    let admin = Keypair::new();
+    let attacker = Keypair::new();
+    let (stablecoin_config, _) = Pubkey::find_program_address(&[b"config"], &program_id);
+    program_test.add_account(stablecoin_config, config_account(admin.pubkey()));
+    program_test.add_account(
+        attacker.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    // Front-run marketplace initialization with a key that is not the stablecoin admin
+    let ix = initialize_marketplace_ix(attacker.pubkey(), stablecoin_config);
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &attacker], recent_blockhash);
+    let result = banks_client.process_transaction(tx).await;
+
+    assert_custom_error(result, rwa_marketplace::RWAMarketplaceError::Unauthorized.into());
+}
+
+#[test]
+fn test_haircut_property_value_applies_class_haircuts() {
+    let mut class_property_value = [0u64; rwa_marketplace::PROPERTY_CLASS_COUNT];
+    class_property_value[PropertyClass::Residential as usize] = 1_000_000;
+    class_property_value[PropertyClass::Land as usize] = 1_000_000;
+    let mut marketplace = Marketplace {
+        admin: Pubkey::new_unique(),
+        stablecoin_config: Pubkey::new_unique(),
+        nft_count: 2,
+        liquidation_threshold: 90,
+        total_property_value: 2_000_000,
+        pending_admin: Pubkey::default(),
+        paused: 0,
+        haircut_bps: rwa_marketplace::DEFAULT_HAIRCUT_BPS,
+        class_property_value,
+    };
+
+    // 20% off residential, 50% off land
+    assert_eq!(marketplace.haircut_property_value().unwrap(), 800_000 + 500_000);
+
+    // A full haircut removes the class from backing entirely
+    marketplace.haircut_bps[PropertyClass::Land as usize] = 10_000;
+    assert_eq!(marketplace.haircut_property_value().unwrap(), 800_000);
+}
+
+#[tokio::test]
+async fn test_first_appraisal_adds_property_to_backing() {
+    let program_id = green_stablecoin::id();
+    let mut program_test = ProgramTest::new(
+        "green_stablecoin",
+        program_id,
+        processor!(green_stablecoin::entry),
+    );
+
+    // A freshly listed property whose owner declared ten times its worth
+    let admin = Keypair::new();
+    let appraiser = Keypair::new();
+    let property_mint = Pubkey::new_unique();
+    let (stablecoin_config, _) = Pubkey::find_program_address(&[b"config"], &program_id);
+    let (marketplace, _) = Pubkey::find_program_address(&[b"marketplace"], &program_id);
+    let (property, _) =
+        Pubkey::find_program_address(&[b"property", property_mint.as_ref()], &program_id);
+    program_test.add_account(stablecoin_config, config_account(admin.pubkey()));
+    program_test.add_account(
+        marketplace,
+        program_account(&Marketplace {
+            admin: admin.pubkey(),
+            stablecoin_config,
+            nft_count: 1,
+            liquidation_threshold: 90,
+            total_property_value: 0,
+            pending_admin: Pubkey::default(),
+            paused: 0,
+            haircut_bps: rwa_marketplace::DEFAULT_HAIRCUT_BPS,
+            class_property_value: [0; rwa_marketplace::PROPERTY_CLASS_COUNT],
+        }),
+    );
+    program_test.add_account(
+        property,
+        program_account(&RealEstateProperty {
+            owner: Pubkey::new_unique(),
+            mint: property_mint,
+            value: 10_000_000,
+            initial_value: 10_000_000,
+            last_valuation_date: 0,
+            location: "Zurich".to_string(),
+            details: "Two-bedroom flat".to_string(),
+            status: AssetStatus::Pending,
+            liquidation_threshold: 90,
+            class: PropertyClass::Residential,
+        }),
+    );
+    let (appraiser_role, appraiser_role_data) = role_account(Role::Appraiser, appraiser.pubkey());
+    program_test.add_account(appraiser_role, appraiser_role_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    // Listing alone adds nothing to the backing
+    let state: Marketplace = program_state(&mut banks_client, marketplace).await;
+    assert_eq!(state.total_property_value, 0);
+
+    let ix = anchor_lang::solana_program::instruction::Instruction {
+        program_id,
+        accounts: anchor_lang::ToAccountMetas::to_account_metas(
+            &green_stablecoin::accounts::UpdateValuation {
+                marketplace,
+                property,
+                appraiser: appraiser.pubkey(),
+                appraiser_role,
+                stablecoin_config,
+            },
+            None,
+        ),
+        data: anchor_lang::InstructionData::data(&green_stablecoin::instruction::UpdateValuation {
+            new_value: 1_000_000,
+        }),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&appraiser])
+        .await
+        .unwrap();
+
+    // The appraised value replaces the declared one and now counts towards backing
+    let property: RealEstateProperty = program_state(&mut banks_client, property).await;
+    assert!(property.status == AssetStatus::Listed);
+    assert_eq!(property.value, 1_000_000);
+    assert_eq!(property.initial_value, 1_000_000);
+    let state: Marketplace = program_state(&mut banks_client, marketplace).await;
+    assert_eq!(state.total_property_value, 1_000_000);
+    assert_eq!(
+        state.class_property_value[PropertyClass::Residential as usize],
+        1_000_000
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_property_waits_for_appraisal() {
+    let program_id = green_stablecoin::id();
+    let mut program_test = ProgramTest::new(
+        "green_stablecoin",
+        program_id,
+        processor!(green_stablecoin::entry),
+    );
+
+    // A listed property written by the first release, without a class
+    let admin = Keypair::new();
+    let owner = Pubkey::new_unique();
+    let property_mint = Pubkey::new_unique();
+    let (marketplace, _) = Pubkey::find_program_address(&[b"marketplace"], &program_id);
+    let (property, _) =
+        Pubkey::find_program_address(&[b"property", property_mint.as_ref()], &program_id);
+    program_test.add_account(
+        marketplace,
+        program_account(&Marketplace {
+            admin: admin.pubkey(),
+            stablecoin_config: Pubkey::new_unique(),
+            nft_count: 1,
+            liquidation_threshold: 90,
+            total_property_value: 0,
+            pending_admin: Pubkey::default(),
+            paused: 0,
+            haircut_bps: rwa_marketplace::DEFAULT_HAIRCUT_BPS,
+            class_property_value: [0; rwa_marketplace::PROPERTY_CLASS_COUNT],
+        }),
+    );
+    let mut data = RealEstateProperty::discriminator().to_vec();
+    (
+        owner,
+        property_mint,
+        2_000_000u64,
+        2_500_000u64,
+        1_700_000_000i64,
+        "Geneva".to_string(),
+        "Office floor".to_string(),
+        AssetStatus::Listed,
+        90u8,
+    )
+        .serialize(&mut data)
+        .unwrap();
+    data.resize(rwa_marketplace::LEGACY_PROPERTY_SPACE, 0);
+    program_test.add_account(
+        property,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    program_test.add_account(
+        admin.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = anchor_lang::solana_program::instruction::Instruction {
+        program_id,
+        accounts: anchor_lang::ToAccountMetas::to_account_metas(
+            &green_stablecoin::accounts::MigrateProperty {
+                marketplace,
+                property,
+                mint: property_mint,
+                admin: admin.pubkey(),
+                system_program: system_program::id(),
+            },
+            None,
+        ),
+        data: anchor_lang::InstructionData::data(&green_stablecoin::instruction::MigrateProperty {
+            property_class: PropertyClass::Commercial,
+        }),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&admin])
+        .await
+        .unwrap();
+
+    // The self-declared value is kept but no longer counts until an Appraiser confirms it
+    let migrated: RealEstateProperty = program_state(&mut banks_client, property).await;
+    assert!(migrated.status == AssetStatus::Pending);
+    assert!(migrated.class == PropertyClass::Commercial);
+    assert_eq!(migrated.owner, owner);
+    assert_eq!(migrated.value, 2_000_000);
+    assert_eq!(migrated.location, "Geneva");
+}
+
 #[tokio::test]
 async fn test_list_rwa() {
     // This test will be completed after we fix any issues in the smart contract
     // It should test the RWA listing functionality, NFT creation, and updates to stablecoin reserves
 }
 
-// Additional tests to be implemented:
-// - test_update_valuation
-// - test_transfer_rwa
-// - test_liquidate_rwa
-// - test_set_liquidation_threshold 
\ No newline at end of file
+fn set_haircut_ix(
+    marketplace: Pubkey,
+    risk_manager: Pubkey,
+    risk_manager_role: Pubkey,
+    stablecoin_config: Pubkey,
+) -> anchor_lang::solana_program::instruction::Instruction {
+    anchor_lang::solana_program::instruction::Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: anchor_lang::ToAccountMetas::to_account_metas(
+            &green_stablecoin::accounts::SetHaircut {
+                marketplace,
+                risk_manager,
+                risk_manager_role,
+                stablecoin_config,
+            },
+            None,
+        ),
+        data: anchor_lang::InstructionData::data(&green_stablecoin::instruction::SetHaircut {
+            property_class: PropertyClass::Commercial,
+            haircut_bps: 4_000,
+        }),
+    }
+}
+
+#[tokio::test]
+async fn test_set_haircut_requires_risk_manager() {
+    let program_id = green_stablecoin::id();
+    let mut program_test = ProgramTest::new(
+        "green_stablecoin",
+        program_id,
+        processor!(green_stablecoin::entry),
+    );
+
+    let liquidator = Keypair::new();
+    let risk_manager = Keypair::new();
+    let stablecoin_config = Pubkey::new_unique();
+    let (marketplace, _) = Pubkey::find_program_address(&[b"marketplace"], &program_id);
+    program_test.add_account(
+        marketplace,
+        program_account(&Marketplace {
+            admin: Pubkey::new_unique(),
+            stablecoin_config,
+            nft_count: 0,
+            liquidation_threshold: 90,
+            total_property_value: 0,
+            pending_admin: Pubkey::default(),
+            paused: 0,
+            haircut_bps: rwa_marketplace::DEFAULT_HAIRCUT_BPS,
+            class_property_value: [0; rwa_marketplace::PROPERTY_CLASS_COUNT],
+        }),
+    );
+    let (liquidator_role, liquidator_role_data) = role_account(Role::Liquidator, liquidator.pubkey());
+    program_test.add_account(liquidator_role, liquidator_role_data);
+    let (risk_manager_role, risk_manager_role_data) =
+        role_account(Role::RiskManager, risk_manager.pubkey());
+    program_test.add_account(risk_manager_role, risk_manager_role_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    // Liquidating no longer carries the right to loosen haircuts
+    let ix = set_haircut_ix(marketplace, liquidator.pubkey(), liquidator_role, stablecoin_config);
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&liquidator]).await;
+    assert_custom_error(result, anchor_lang::error::ErrorCode::ConstraintSeeds.into());
+
+    let ix = set_haircut_ix(marketplace, risk_manager.pubkey(), risk_manager_role, stablecoin_config);
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&risk_manager])
+        .await
+        .unwrap();
+
+    let state: Marketplace = program_state(&mut banks_client, marketplace).await;
+    assert_eq!(state.haircut_bps[PropertyClass::Commercial as usize], 4_000);
+}
+
+#[tokio::test]
+async fn test_liquidate_rwa_through_approved_proposal() {
+    let (mut program_test, fixture) = setup();
+    let program_id = green_stablecoin::id();
+
+    // An at-risk residential property whose owner approved the property PDA at listing
+    let (marketplace, marketplace_data) =
+        marketplace_account(&fixture, [1_000_000, 0, 0, 0, 0]);
+    program_test.add_account(marketplace, marketplace_data);
+    let owner = Pubkey::new_unique();
+    let property_mint = Pubkey::new_unique();
+    let (property, _) =
+        Pubkey::find_program_address(&[b"property", property_mint.as_ref()], &program_id);
+    program_test.add_account(property_mint, mint_account(Pubkey::new_unique()));
+    program_test.add_account(
+        property,
+        program_account(&RealEstateProperty {
+            owner,
+            mint: property_mint,
+            value: 1_000_000,
+            initial_value: 1_200_000,
+            last_valuation_date: 0,
+            location: "Zurich".to_string(),
+            details: "Two-bedroom flat".to_string(),
+            status: AssetStatus::AtRisk,
+            liquidation_threshold: 90,
+            class: PropertyClass::Residential,
+        }),
+    );
+    let owner_token_account = Pubkey::new_unique();
+    program_test.add_account(
+        owner_token_account,
+        delegated_token_account(property_mint, owner, 1, property, 1),
+    );
+    let liquidator = Keypair::new();
+    program_test.add_account(
+        liquidator.pubkey(),
+        Account::new(1_000_000_000, 0, &system_program::ID),
+    );
+    let (liquidator_role, liquidator_role_data) = role_account(Role::Liquidator, liquidator.pubkey());
+    program_test.add_account(liquidator_role, liquidator_role_data);
+    let (proposal, proposal_data) = proposal_account(
+        0,
+        PrivilegedAction::LiquidateRwa { property },
+        fixture.admin.pubkey(),
+    );
+    program_test.add_account(proposal, proposal_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let admin_token_account = anchor_spl::associated_token::get_associated_token_address(
+        &fixture.admin.pubkey(),
+        &property_mint,
+    );
+    let ix = anchor_lang::solana_program::instruction::Instruction {
+        program_id,
+        accounts: anchor_lang::ToAccountMetas::to_account_metas(
+            &green_stablecoin::accounts::LiquidateRWA {
+                marketplace,
+                property,
+                admin_set: fixture.admin_set,
+                proposal,
+                liquidator: liquidator.pubkey(),
+                liquidator_role,
+                owner,
+                owner_token_account,
+                admin: fixture.admin.pubkey(),
+                admin_token_account,
+                mint: property_mint,
+                stablecoin_config: fixture.config,
+                token_program: token::ID,
+                associated_token_program: anchor_spl::associated_token::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::id(),
+            },
+            None,
+        ),
+        data: anchor_lang::InstructionData::data(&green_stablecoin::instruction::LiquidateRwa {}),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&liquidator])
+        .await
+        .unwrap();
+
+    // The NFT is seized to the admin and the property leaves the backing
+    assert_eq!(token_balance(&mut banks_client, owner_token_account).await, 0);
+    assert_eq!(token_balance(&mut banks_client, admin_token_account).await, 1);
+    let property: RealEstateProperty = program_state(&mut banks_client, property).await;
+    assert!(property.status == AssetStatus::Liquidated);
+    let state: Marketplace = program_state(&mut banks_client, marketplace).await;
+    assert_eq!(state.total_property_value, 0);
+    assert_eq!(state.class_property_value[PropertyClass::Residential as usize], 0);
+}
+
+#[tokio::test]
+async fn test_marketplace_admin_handover_takes_effect_on_accept() {
+    let (mut program_test, fixture) = setup();
+    let program_id = green_stablecoin::id();
+
+    let (marketplace, marketplace_data) = marketplace_account(&fixture, [0; 5]);
+    program_test.add_account(marketplace, marketplace_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let new_admin = Keypair::new();
+    let propose = anchor_lang::solana_program::instruction::Instruction {
+        program_id,
+        // Listed by hand: the stablecoin's own ProposeAdmin accounts share the client struct name
+        accounts: vec![
+            AccountMeta::new(marketplace, false),
+            AccountMeta::new_readonly(fixture.admin.pubkey(), true),
+        ],
+        data: anchor_lang::InstructionData::data(&green_stablecoin::instruction::ProposeMarketplaceAdmin {
+            new_admin: new_admin.pubkey(),
+        }),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, propose, &[&fixture.admin])
+        .await
+        .unwrap();
+
+    // Proposing alone hands nothing over
+    let state: Marketplace = program_state(&mut banks_client, marketplace).await;
+    assert_eq!(state.admin, fixture.admin.pubkey());
+    assert_eq!(state.pending_admin, new_admin.pubkey());
+
+    let accept = anchor_lang::solana_program::instruction::Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(marketplace, false),
+            AccountMeta::new_readonly(new_admin.pubkey(), true),
+        ],
+        data: anchor_lang::InstructionData::data(&green_stablecoin::instruction::AcceptMarketplaceAdmin {}),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, accept, &[&new_admin])
+        .await
+        .unwrap();
+
+    let state: Marketplace = program_state(&mut banks_client, marketplace).await;
+    assert_eq!(state.admin, new_admin.pubkey());
+    assert_eq!(state.pending_admin, Pubkey::default());
+}
@@ -1,92 +1,2225 @@
+mod common;
+
 use anchor_lang::prelude::*;
+use anchor_lang::{Discriminator, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use common::*;
 use green_stablecoin::program::GreenStablecoin;
-use green_stablecoin::{self, StablecoinTransfer, Config};
+use green_stablecoin::multisig::{MultisigError, Proposal};
+use green_stablecoin::oracle::OracleError;
+use green_stablecoin::proof_of_reserves::{self, ReserveSnapshot};
+use green_stablecoin::psm::{Psm, PsmError};
+use green_stablecoin::rwa_marketplace::{AssetStatus, PropertyClass, RealEstateProperty};
+use green_stablecoin::{self, StablecoinTransfer, Blacklist, Config, FeeBeneficiary, FeeExemption, FeeSplit, PrivilegedAction, Role, StablecoinError};
+use solana_program::program_option::COption;
+use solana_program::program_pack::Pack;
 use solana_program::system_program;
 use solana_program_test::*;
 use solana_sdk::{
     account::Account,
+    instruction::Instruction,
     signature::{Keypair, Signer},
     transaction::Transaction,
     transport::TransportError,
 };
 
+fn initialize_ix(
+    admin: Pubkey,
+    mint: Pubkey,
+    usdt_mint: Pubkey,
+    dao_token_account: Pubkey,
+    usdt_oracle: Pubkey,
+    decimals: u8,
+) -> Instruction {
+    let program_id = green_stablecoin::id();
+    let pda = |seed: &[u8]| Pubkey::find_program_address(&[seed], &program_id).0;
+    Instruction {
+        program_id,
+        accounts: green_stablecoin::accounts::Initialize {
+            config: pda(b"config"),
+            fee_split: pda(b"fee_split"),
+            admin,
+            mint,
+            mint_authority: pda(b"mint_authority"),
+            freeze_authority: pda(b"freeze_authority"),
+            usdt_oracle,
+            revenue_vault: pda(b"revenue_vault"),
+            vault_authority: pda(b"vault_authority"),
+            system_program: system_program::id(),
+            token_program: token::ID,
+            rent: sysvar::rent::id(),
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::Initialize {
+            usdt_mint,
+            dao_token_account,
+            decimals,
+        }
+        .data(),
+    }
+}
+
 #[tokio::test]
 async fn test_stablecoin_initialize() {
-    // Set up program test
-    let program_id = Pubkey::new_unique();
+    let program_id = green_stablecoin::id();
     let mut program_test = ProgramTest::new(
         "green_stablecoin",
         program_id,
         processor!(green_stablecoin::entry),
     );
 
-    // Generate necessary keypairs
+    // A fresh 6-decimal mint still controlled by the admin
     let admin = Keypair::new();
-    let mint_authority = Keypair::new();
-    let mint = Keypair::new();
-    let usdt_mint = Keypair::new();
-    let dao_token_account = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let usdt_mint = Pubkey::new_unique();
+    let dao_token_account = Pubkey::new_unique();
+    let usdt_oracle = Pubkey::new_unique();
+    program_test.add_account(
+        admin.pubkey(),
+        Account::new(1_000_000_000, 0, &system_program::id()),
+    );
+    program_test.add_account(mint, mint_account(admin.pubkey()));
+    program_test.add_account(usdt_oracle, price_account(100_000_000, -8));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = initialize_ix(admin.pubkey(), mint, usdt_mint, dao_token_account, usdt_oracle, 6);
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&admin])
+        .await
+        .unwrap();
+
+    let (config_pda, _) = Pubkey::find_program_address(&[b"config"], &program_id);
+    let config: Config = program_state(&mut banks_client, config_pda).await;
+    assert_eq!(config.admin, admin.pubkey());
+    assert_eq!(config.usdt_mint, usdt_mint);
+    assert_eq!(config.dao_token_account, dao_token_account);
+    assert_eq!(config.mint, mint);
+    assert_eq!(config.decimals, 6);
+    assert_eq!(config.total_supply, 1_000_000);
+    assert_eq!(config.usdt_oracle, usdt_oracle);
+
+    // Both mint authorities now belong to program PDAs
+    let (mint_authority, _) = Pubkey::find_program_address(&[b"mint_authority"], &program_id);
+    let mint = banks_client.get_account(mint).await.unwrap().unwrap();
+    let mint = spl_token::state::Mint::unpack(&mint.data).unwrap();
+    assert_eq!(mint.mint_authority, COption::Some(mint_authority));
+}
+
+#[tokio::test]
+async fn test_initialize_rejects_decimals_other_than_the_mint() {
+    let program_id = green_stablecoin::id();
+    let mut program_test = ProgramTest::new(
+        "green_stablecoin",
+        program_id,
+        processor!(green_stablecoin::entry),
+    );
 
-    // Add accounts to test environment
+    let admin = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let usdt_oracle = Pubkey::new_unique();
     program_test.add_account(
         admin.pubkey(),
-        Account {
-            lamports: 1_000_000_000,
-            data: vec![],
-            owner: system_program::id(),
-            executable: false,
-            rent_epoch: 0,
+        Account::new(1_000_000_000, 0, &system_program::id()),
+    );
+    program_test.add_account(mint, mint_account(admin.pubkey()));
+    program_test.add_account(usdt_oracle, price_account(100_000_000, -8));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    // The mint has 6 decimals; 8 would scale every deposit and redemption by 100
+    let ix = initialize_ix(
+        admin.pubkey(),
+        mint,
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        usdt_oracle,
+        8,
+    );
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&admin]).await;
+
+    assert_custom_error(result, StablecoinError::InvalidDecimals.into());
+}
+
+#[tokio::test]
+async fn test_initialize_rejects_oracle_not_owned_by_pyth() {
+    let program_id = green_stablecoin::id();
+    let mut program_test = ProgramTest::new(
+        "green_stablecoin",
+        program_id,
+        processor!(green_stablecoin::entry),
+    );
+
+    // A well-formed price account that anyone could have written
+    let admin = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let usdt_oracle = Pubkey::new_unique();
+    program_test.add_account(
+        admin.pubkey(),
+        Account::new(1_000_000_000, 0, &system_program::id()),
+    );
+    program_test.add_account(mint, mint_account(admin.pubkey()));
+    let mut oracle_data = price_account(100_000_000, -8);
+    oracle_data.owner = Pubkey::new_unique();
+    program_test.add_account(usdt_oracle, oracle_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = initialize_ix(
+        admin.pubkey(),
+        mint,
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        usdt_oracle,
+        6,
+    );
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&admin]).await;
+
+    assert_custom_error(result, OracleError::InvalidOracleOwner.into());
+}
+
+#[tokio::test]
+async fn test_transfer_rejects_substituted_dao_account() {
+    let (program_test, fixture) = setup();
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    // Route the fee to an attacker-controlled token account of the same mint
+    let mut accounts = transfer_accounts(&fixture);
+    accounts.dao_token_account = fixture.attacker_token_account;
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: accounts.to_account_metas(None),
+        data: green_stablecoin::instruction::Transfer { amount: 10_000 }.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender]).await;
+
+    assert_custom_error(result, StablecoinError::InvalidDaoAccount.into());
+}
+
+#[tokio::test]
+async fn test_transfer_rejects_config_outside_pda() {
+    let (mut program_test, fixture) = setup();
+
+    // A look-alike config owned by the program but not at the config PDA
+    let fake_config = Pubkey::new_unique();
+    program_test.add_account(
+        fake_config,
+        program_account(&Config {
+            admin: fixture.sender.pubkey(),
+            dao_token_account: fixture.attacker_token_account,
+            ..base_config(&fixture)
+        }),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let mut accounts = transfer_accounts(&fixture);
+    accounts.config = fake_config;
+    accounts.dao_token_account = fixture.attacker_token_account;
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: accounts.to_account_metas(None),
+        data: green_stablecoin::instruction::Transfer { amount: 10_000 }.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender]).await;
+
+    assert_custom_error(result, anchor_lang::error::ErrorCode::ConstraintSeeds.into());
+}
+
+#[tokio::test]
+async fn test_transfer_rejects_foreign_sender_token_account() {
+    let (program_test, fixture) = setup();
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    // The sender signs but spends from a token account owned by someone else
+    let mut accounts = transfer_accounts(&fixture);
+    accounts.sender_token_account = fixture.dao_token_account;
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: accounts.to_account_metas(None),
+        data: green_stablecoin::instruction::Transfer { amount: 10_000 }.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender]).await;
+
+    assert_custom_error(result, StablecoinError::Unauthorized.into());
+}
+
+#[tokio::test]
+async fn test_mint_updates_supply_and_collateral_ratio() {
+    let (mut program_test, fixture) = setup();
+
+    let (proposal, proposal_data) = proposal_account(
+        0,
+        PrivilegedAction::Mint {
+            recipient_token_account: fixture.recipient_token_account,
+            amount: 1_000,
+        },
+        fixture.admin.pubkey(),
+    );
+    program_test.add_account(proposal, proposal_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: mint_accounts(&fixture, proposal),
+        data: green_stablecoin::instruction::Mint { amount: 1_000 }.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.admin])
+        .await
+        .unwrap();
+
+    assert_eq!(token_balance(&mut banks_client, fixture.recipient_token_account).await, 1_000);
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.total_supply, 1_001_000);
+    // 2,000,000 of reserves after haircuts now back 1,001,000 Establo
+    let status = reserve_status(&mut banks_client, &payer, recent_blockhash, &fixture).await;
+    assert_eq!(status.total_supply, 1_001_000);
+    assert_eq!(status.collateral_ratio_bps, 2_000_000 * 10_000 / 1_001_000);
+}
+
+#[tokio::test]
+async fn test_mint_rejects_substituted_mint() {
+    let (mut program_test, fixture) = setup();
+
+    // Another mint that also trusts the mint authority PDA
+    let other_mint = Pubkey::new_unique();
+    let other_token_account = Pubkey::new_unique();
+    program_test.add_account(other_mint, mint_account(fixture.mint_authority));
+    program_test.add_account(
+        other_token_account,
+        token_account(other_mint, fixture.admin.pubkey(), 0),
+    );
+
+    // The multisig approved this mint, so only the account checks stand in the way
+    let (proposal, proposal_data) = proposal_account(
+        0,
+        PrivilegedAction::Mint {
+            recipient_token_account: other_token_account,
+            amount: 1,
         },
+        fixture.admin.pubkey(),
     );
+    program_test.add_account(proposal, proposal_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::MintTokens {
+            config: fixture.config,
+            admin_set: fixture.admin_set,
+            proposal,
+            minter: fixture.admin.pubkey(),
+            minter_role: fixture.minter_role,
+            mint: other_mint,
+            recipient_token_account: other_token_account,
+            recipient_blacklist: blacklist_address(fixture.admin.pubkey()),
+            mint_authority: fixture.mint_authority,
+            usdt_oracle: fixture.usdt_oracle,
+            token_program: token::ID,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::Mint { amount: 1 }.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.admin]).await;
+
+    assert_custom_error(result, StablecoinError::InvalidMint.into());
+}
+
+#[tokio::test]
+async fn test_expired_proposal_cannot_execute() {
+    let (mut program_test, fixture) = setup();
 
-    // Start the test environment
+    // Fully approved, but past its expiry
+    let action = PrivilegedAction::Mint {
+        recipient_token_account: fixture.recipient_token_account,
+        amount: 1_000,
+    };
+    let (proposal, _) = proposal_account(0, action.clone(), fixture.admin.pubkey());
+    program_test.add_account(
+        proposal,
+        program_account(&Proposal {
+            index: 0,
+            proposer: fixture.admin.pubkey(),
+            action,
+            approvals: vec![fixture.admin.pubkey()],
+            executed: false,
+            created_at: 0,
+            expires_at: 1,
+        }),
+    );
     let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
 
-    // Find PDA for config
-    let (config_pda, bump) = Pubkey::find_program_address(&[b"config"], &program_id);
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: mint_accounts(&fixture, proposal),
+        data: green_stablecoin::instruction::Mint { amount: 1_000 }.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.admin]).await;
 
-    // Create initialize instruction
-    let ix = anchor_lang::solana_program::instruction::Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new(config_pda, false),
-            AccountMeta::new(admin.pubkey(), true),
-            AccountMeta::new(mint.pubkey(), false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(token::ID, false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-        ],
-        data: anchor_lang::InstructionData::new(
-            anchor_lang::Discriminator::new("global", "initialize"),
-            green_stablecoin::instruction::Initialize {
-                usdt_mint: usdt_mint.pubkey(),
-                dao_token_account: dao_token_account.pubkey(),
-                decimals: 6,
+    assert_custom_error(result, MultisigError::ProposalExpired.into());
+}
+
+#[tokio::test]
+async fn test_cancel_proposal_by_signer() {
+    let (mut program_test, fixture) = setup();
+
+    let (proposal, proposal_data) = proposal_account(
+        0,
+        PrivilegedAction::Mint {
+            recipient_token_account: fixture.recipient_token_account,
+            amount: 1_000,
+        },
+        fixture.admin.pubkey(),
+    );
+    program_test.add_account(proposal, proposal_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let cancel = |signer: Pubkey| Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::CancelProposal {
+            admin_set: fixture.admin_set,
+            proposal,
+            proposer: fixture.admin.pubkey(),
+            signer,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::CancelProposal {}.data(),
+    };
+
+    let outsider = Keypair::new();
+    let result = process(&mut banks_client, &payer, recent_blockhash, cancel(outsider.pubkey()), &[&outsider]).await;
+    assert_custom_error(result, MultisigError::NotASigner.into());
+
+    process(&mut banks_client, &payer, recent_blockhash, cancel(fixture.admin.pubkey()), &[&fixture.admin])
+        .await
+        .unwrap();
+
+    // The proposal is closed, so nothing is left to execute
+    assert!(banks_client.get_account(proposal).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_burn_rejects_token_account_of_other_mint() {
+    let (mut program_test, fixture) = setup();
+
+    let other_mint = Pubkey::new_unique();
+    let other_token_account = Pubkey::new_unique();
+    program_test.add_account(other_mint, mint_account(fixture.admin.pubkey()));
+    program_test.add_account(
+        other_token_account,
+        token_account(other_mint, fixture.admin.pubkey(), 1_000),
+    );
+    let (proposal, proposal_data) = proposal_account(
+        1,
+        PrivilegedAction::Burn {
+            token_account: other_token_account,
+            amount: 1_000,
+        },
+        fixture.admin.pubkey(),
+    );
+    program_test.add_account(proposal, proposal_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::BurnTokens {
+            config: fixture.config,
+            admin_set: fixture.admin_set,
+            proposal,
+            burner: fixture.admin.pubkey(),
+            burner_role: fixture.burner_role,
+            mint_authority: fixture.mint_authority,
+            mint: fixture.mint,
+            token_account: other_token_account,
+            token_program: token::ID,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::Burn { amount: 1_000 }.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.admin]).await;
+
+    assert_custom_error(result, StablecoinError::InvalidMint.into());
+}
+
+#[tokio::test]
+async fn test_burn_through_delegation_to_mint_authority() {
+    let (mut program_test, fixture) = setup();
+
+    // The holder has approved the mint authority PDA for 1,000 of its 5,000 Establo
+    let holder = Pubkey::new_unique();
+    let holder_token_account = Pubkey::new_unique();
+    program_test.add_account(
+        holder_token_account,
+        delegated_token_account(fixture.mint, holder, 5_000, fixture.mint_authority, 1_000),
+    );
+    let burn_proposal = |index: u64, amount: u64| {
+        proposal_account(
+            index,
+            PrivilegedAction::Burn {
+                token_account: holder_token_account,
+                amount,
             },
+            fixture.admin.pubkey(),
         )
-        .to_vec(),
     };
+    let (proposal, proposal_data) = burn_proposal(0, 1_000);
+    program_test.add_account(proposal, proposal_data);
+    let (over_delegation_proposal, over_delegation_proposal_data) = burn_proposal(1, 2_000);
+    program_test.add_account(over_delegation_proposal, over_delegation_proposal_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
 
-    // Create and sign transaction
-    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
-    tx.sign(&[&payer, &admin], recent_blockhash);
+    let burn = |proposal: Pubkey, amount: u64| Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::BurnTokens {
+            config: fixture.config,
+            admin_set: fixture.admin_set,
+            proposal,
+            burner: fixture.admin.pubkey(),
+            burner_role: fixture.burner_role,
+            mint_authority: fixture.mint_authority,
+            mint: fixture.mint,
+            token_account: holder_token_account,
+            token_program: token::ID,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::Burn { amount }.data(),
+    };
 
-    // Process transaction
-    banks_client.process_transaction(tx).await.unwrap();
+    // An approved burn still can't exceed what the holder delegated
+    let result = process(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        burn(over_delegation_proposal, 2_000),
+        &[&fixture.admin],
+    )
+    .await;
+    assert_custom_error(result, StablecoinError::BurnNotDelegated.into());
 
-    // Verify config account was initialized correctly
-    let config_account = banks_client.get_account(config_pda).await.unwrap().unwrap();
-    let config = Config::try_deserialize(&mut config_account.data.as_ref()).unwrap();
-    assert_eq!(config.admin, admin.pubkey());
-    assert_eq!(config.usdt_mint, usdt_mint.pubkey());
-    assert_eq!(config.dao_token_account, dao_token_account.pubkey());
-    assert_eq!(config.decimals, 6);
+    process(&mut banks_client, &payer, recent_blockhash, burn(proposal, 1_000), &[&fixture.admin])
+        .await
+        .unwrap();
+
+    assert_eq!(token_balance(&mut banks_client, holder_token_account).await, 4_000);
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.total_supply, 999_000);
+}
+
+#[tokio::test]
+async fn test_set_fee_requires_fee_manager_role() {
+    let (program_test, fixture) = setup();
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    // The admin no longer manages fees unless granted the FeeManager role
+    let (fee_manager_role, _) = role_account(Role::FeeManager, fixture.admin.pubkey());
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::SetFee {
+            config: fixture.config,
+            fee_manager: fixture.admin.pubkey(),
+            fee_manager_role,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::SetFee {
+            fee_bps: 100,
+            min_fee: 0,
+            fee_tiers: vec![],
+        }
+        .data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.admin]).await;
+
+    assert_custom_error(
+        result,
+        anchor_lang::error::ErrorCode::AccountNotInitialized.into(),
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_rejected_while_paused() {
+    let (mut program_test, fixture) = setup();
+
+    // Re-adding the config overrides the fixture's copy with transfers paused
+    program_test.add_account(
+        fixture.config,
+        program_account(&Config {
+            paused: green_stablecoin::PAUSE_TRANSFER,
+            ..base_config(&fixture)
+        }),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: transfer_accounts(&fixture).to_account_metas(None),
+        data: green_stablecoin::instruction::Transfer { amount: 10_000 }.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender]).await;
+
+    assert_custom_error(result, StablecoinError::Paused.into());
+}
+
+#[tokio::test]
+async fn test_mint_rejected_above_window_limit() {
+    let (mut program_test, fixture) = setup();
+
+    // Cap minting at 1,000 per day, then try to mint more in a single approved proposal
+    program_test.add_account(
+        fixture.config,
+        program_account(&Config {
+            mint_limit: 1_000,
+            ..base_config(&fixture)
+        }),
+    );
+    let (proposal, proposal_data) = proposal_account(
+        0,
+        PrivilegedAction::Mint {
+            recipient_token_account: fixture.recipient_token_account,
+            amount: 1_001,
+        },
+        fixture.admin.pubkey(),
+    );
+    program_test.add_account(proposal, proposal_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: mint_accounts(&fixture, proposal),
+        data: green_stablecoin::instruction::Mint { amount: 1_001 }.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.admin]).await;
+
+    assert_custom_error(result, StablecoinError::RateLimitExceeded.into());
+}
+
+#[tokio::test]
+async fn test_transfer_rejects_blacklisted_sender() {
+    let (mut program_test, fixture) = setup();
+
+    let accounts = transfer_accounts(&fixture);
+    program_test.add_account(
+        accounts.sender_blacklist,
+        program_account(&Blacklist {
+            wallet: fixture.sender.pubkey(),
+        }),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: accounts.to_account_metas(None),
+        data: green_stablecoin::instruction::Transfer { amount: 10_000 }.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender]).await;
+
+    assert_custom_error(result, StablecoinError::Blacklisted.into());
+}
+
+#[tokio::test]
+async fn test_mint_rejects_blacklisted_recipient() {
+    let (mut program_test, fixture) = setup();
+
+    program_test.add_account(
+        blacklist_address(fixture.recipient),
+        program_account(&Blacklist {
+            wallet: fixture.recipient,
+        }),
+    );
+    let (proposal, proposal_data) = proposal_account(
+        0,
+        PrivilegedAction::Mint {
+            recipient_token_account: fixture.recipient_token_account,
+            amount: 1_000,
+        },
+        fixture.admin.pubkey(),
+    );
+    program_test.add_account(proposal, proposal_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: mint_accounts(&fixture, proposal),
+        data: green_stablecoin::instruction::Mint { amount: 1_000 }.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.admin]).await;
+
+    assert_custom_error(result, StablecoinError::Blacklisted.into());
+}
+
+#[tokio::test]
+async fn test_blacklisted_wallet_cannot_deposit_redeem_or_swap() {
+    let (mut program_test, fixture) = setup();
+
+    // A KYC'd sender with USDT to spend, blacklisted after onboarding
+    program_test.add_account(
+        blacklist_address(fixture.sender.pubkey()),
+        program_account(&Blacklist {
+            wallet: fixture.sender.pubkey(),
+        }),
+    );
+    let (psm, psm_data) = psm_account(&fixture, 1_000_000);
+    program_test.add_account(psm, psm_data);
+    let user_usdt_account = Pubkey::new_unique();
+    program_test.add_account(
+        user_usdt_account,
+        token_account(fixture.usdt_mint, fixture.sender.pubkey(), 10_000),
+    );
+    let (attestation, attestation_data) = attestation_account(fixture.sender.pubkey(), i64::MAX);
+    program_test.add_account(attestation, attestation_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let instructions = [
+        Instruction {
+            program_id: green_stablecoin::id(),
+            accounts: deposit_accounts(&fixture, attestation, user_usdt_account),
+            data: green_stablecoin::instruction::DepositAndMint { usdt_amount: 10_000 }.data(),
+        },
+        Instruction {
+            program_id: green_stablecoin::id(),
+            accounts: redeem_accounts(&fixture, attestation, user_usdt_account),
+            data: green_stablecoin::instruction::Redeem { amount: 10_000 }.data(),
+        },
+        Instruction {
+            program_id: green_stablecoin::id(),
+            accounts: swap_in_accounts(&fixture, psm, attestation, fixture.usdt_mint, user_usdt_account),
+            data: green_stablecoin::instruction::SwapIn {
+                collateral_amount: 10_000,
+            }
+            .data(),
+        },
+    ];
+
+    for ix in instructions {
+        let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender]).await;
+        assert_custom_error(result, StablecoinError::Blacklisted.into());
+    }
+}
+
+#[tokio::test]
+async fn test_freeze_and_thaw_token_account_by_compliance() {
+    let (mut program_test, fixture) = setup();
+    let program_id = green_stablecoin::id();
+
+    // The freeze authority has been handed to its PDA
+    let (freeze_authority, _) = Pubkey::find_program_address(&[b"freeze_authority"], &program_id);
+    let mut mint_data = mint_account(fixture.mint_authority);
+    spl_token::state::Mint {
+        mint_authority: COption::Some(fixture.mint_authority),
+        supply: 1_000_000,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: COption::Some(freeze_authority),
+    }
+    .pack_into_slice(&mut mint_data.data);
+    program_test.add_account(fixture.mint, mint_data);
+    let compliance = Keypair::new();
+    let (compliance_role, compliance_role_data) = role_account(Role::Compliance, compliance.pubkey());
+    program_test.add_account(compliance_role, compliance_role_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let accounts = green_stablecoin::accounts::FreezeTokenAccount {
+        config: fixture.config,
+        compliance: compliance.pubkey(),
+        compliance_role,
+        mint: fixture.mint,
+        token_account: fixture.sender_token_account,
+        freeze_authority,
+        token_program: token::ID,
+    };
+    let freeze = Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(None),
+        data: green_stablecoin::instruction::FreezeAccount {}.data(),
+    };
+    let thaw = Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(None),
+        data: green_stablecoin::instruction::ThawAccount {}.data(),
+    };
+    let token_state = |account: Account| spl_token::state::Account::unpack(&account.data).unwrap().state;
+
+    process(&mut banks_client, &payer, recent_blockhash, freeze, &[&compliance])
+        .await
+        .unwrap();
+    let account = banks_client.get_account(fixture.sender_token_account).await.unwrap().unwrap();
+    assert_eq!(token_state(account), spl_token::state::AccountState::Frozen);
+
+    // A frozen account can't send
+    let transfer = Instruction {
+        program_id,
+        accounts: transfer_accounts(&fixture).to_account_metas(None),
+        data: green_stablecoin::instruction::Transfer { amount: 10_000 }.data(),
+    };
+    let result = process(&mut banks_client, &payer, recent_blockhash, transfer, &[&fixture.sender]).await;
+    assert!(result.is_err());
+
+    process(&mut banks_client, &payer, recent_blockhash, thaw, &[&compliance])
+        .await
+        .unwrap();
+    let account = banks_client.get_account(fixture.sender_token_account).await.unwrap().unwrap();
+    assert_eq!(token_state(account), spl_token::state::AccountState::Initialized);
+}
+
+#[tokio::test]
+async fn test_redeem_rejects_expired_attestation() {
+    let (mut program_test, fixture) = setup();
+    let program_id = green_stablecoin::id();
+
+    // The fixture's USDT reserve vault is funded, but the sender's attestation has lapsed
+    let holder_usdt_account = Pubkey::new_unique();
+    program_test.add_account(
+        holder_usdt_account,
+        token_account(fixture.usdt_mint, fixture.sender.pubkey(), 0),
+    );
+    let (attestation, attestation_data) = attestation_account(fixture.sender.pubkey(), 1);
+    program_test.add_account(attestation, attestation_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id,
+        accounts: redeem_accounts(&fixture, attestation, holder_usdt_account),
+        data: green_stablecoin::instruction::Redeem { amount: 10_000 }.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender]).await;
+
+    assert_custom_error(result, StablecoinError::AttestationExpired.into());
 }
 
-// Additional tests to be implemented:
-// - test_stablecoin_transfer
-// - test_mint_tokens
-// - test_burn_tokens
-// - test_update_reserves
-// - test_update_dao_account 
\ No newline at end of file
+#[tokio::test]
+async fn test_redeem_window_counts_amount_including_fee() {
+    let (mut program_test, fixture) = setup();
+
+    // A 0.5% redeem fee and a window cap equal to the net amount of a 10,000 redemption
+    program_test.add_account(
+        fixture.config,
+        program_account(&Config {
+            redeem_fee_bps: 50,
+            redeem_limit: 9_950,
+            ..base_config(&fixture)
+        }),
+    );
+    let holder_usdt_account = Pubkey::new_unique();
+    program_test.add_account(
+        holder_usdt_account,
+        token_account(fixture.usdt_mint, fixture.sender.pubkey(), 0),
+    );
+    let (attestation, attestation_data) = attestation_account(fixture.sender.pubkey(), i64::MAX);
+    program_test.add_account(attestation, attestation_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let redeem = |amount: u64| Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: redeem_accounts(&fixture, attestation, holder_usdt_account),
+        data: green_stablecoin::instruction::Redeem { amount }.data(),
+    };
+
+    // The fee does not escape the cap
+    let result = process(&mut banks_client, &payer, recent_blockhash, redeem(10_000), &[&fixture.sender]).await;
+    assert_custom_error(result, StablecoinError::RateLimitExceeded.into());
+
+    process(&mut banks_client, &payer, recent_blockhash, redeem(9_950), &[&fixture.sender])
+        .await
+        .unwrap();
+
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.redeemed_in_window, 9_950);
+    assert_eq!(config.redeem_fee_revenue, 49);
+    assert_eq!(token_balance(&mut banks_client, holder_usdt_account).await, 9_901);
+}
+
+#[tokio::test]
+async fn test_sync_reserves_by_reserve_manager() {
+    let (mut program_test, fixture) = setup();
+
+    let reserve_manager = Keypair::new();
+    let (reserve_manager_role, reserve_manager_role_data) =
+        role_account(Role::ReserveManager, reserve_manager.pubkey());
+    program_test.add_account(reserve_manager_role, reserve_manager_role_data);
+    // The vault holds more USDT than the config has accounted for
+    program_test.add_account(
+        fixture.usdt_vault,
+        token_account(fixture.usdt_mint, fixture.vault_authority, 1_200_000),
+    );
+    // 1,000,000 residential and 400,000 land, after 20% and 50% haircuts
+    let (marketplace, marketplace_data) =
+        marketplace_account(&fixture, [1_000_000, 0, 0, 400_000, 0]);
+    program_test.add_account(marketplace, marketplace_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let sync = |reserve_manager: Pubkey| Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::SyncReserves {
+            config: fixture.config,
+            reserve_manager,
+            reserve_manager_role,
+            usdt_mint: fixture.usdt_mint,
+            usdt_vault: fixture.usdt_vault,
+            marketplace,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::SyncReserves {}.data(),
+    };
+
+    // The role PDA is bound to the signer holding it
+    let outsider = Keypair::new();
+    let result = process(&mut banks_client, &payer, recent_blockhash, sync(outsider.pubkey()), &[&outsider]).await;
+    assert!(result.is_err());
+
+    process(&mut banks_client, &payer, recent_blockhash, sync(reserve_manager.pubkey()), &[&reserve_manager])
+        .await
+        .unwrap();
+
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.usdt_reserve, 1_200_000);
+    assert_eq!(config.real_estate_value, 1_400_000);
+    assert_eq!(config.haircut_real_estate_value, 800_000 + 200_000);
+}
+
+#[tokio::test]
+async fn test_mint_halted_when_oracle_confidence_is_wide() {
+    let (mut program_test, fixture) = setup();
+
+    // $1.00 give or take $0.05, wider than the 1% the program accepts
+    let mut oracle_data = price_account(100_000_000, -8);
+    let conf_offset = green_stablecoin::oracle::PYTH_AGG_CONF_OFFSET;
+    oracle_data.data[conf_offset..conf_offset + 8].copy_from_slice(&5_000_000u64.to_le_bytes());
+    program_test.add_account(fixture.usdt_oracle, oracle_data);
+    let (proposal, proposal_data) = proposal_account(
+        0,
+        PrivilegedAction::Mint {
+            recipient_token_account: fixture.recipient_token_account,
+            amount: 1,
+        },
+        fixture.admin.pubkey(),
+    );
+    program_test.add_account(proposal, proposal_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: mint_accounts(&fixture, proposal),
+        data: green_stablecoin::instruction::Mint { amount: 1 }.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.admin]).await;
+
+    assert_custom_error(result, OracleError::ConfidenceTooWide.into());
+}
+
+#[tokio::test]
+async fn test_mint_halted_when_usdt_depegs() {
+    let (mut program_test, fixture) = setup();
+
+    // USDT trades at $0.95, below the $0.98 floor
+    program_test.add_account(fixture.usdt_oracle, price_account(95_000_000, -8));
+    let (proposal, proposal_data) = proposal_account(
+        0,
+        PrivilegedAction::Mint {
+            recipient_token_account: fixture.recipient_token_account,
+            amount: 1,
+        },
+        fixture.admin.pubkey(),
+    );
+    program_test.add_account(proposal, proposal_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: mint_accounts(&fixture, proposal),
+        data: green_stablecoin::instruction::Mint { amount: 1 }.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.admin]).await;
+
+    assert_custom_error(result, OracleError::UsdtDepegged.into());
+}
+
+#[tokio::test]
+async fn test_mint_halted_on_stale_usdt_price() {
+    let (mut program_test, fixture) = setup();
+
+    // USDT still prints $1.00, but the last update is two hours old against a one hour limit
+    let two_hours_ago = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+        - 7_200;
+    program_test.add_account(fixture.usdt_oracle, price_account_at(100_000_000, -8, two_hours_ago));
+    let (proposal, proposal_data) = proposal_account(
+        0,
+        PrivilegedAction::Mint {
+            recipient_token_account: fixture.recipient_token_account,
+            amount: 1,
+        },
+        fixture.admin.pubkey(),
+    );
+    program_test.add_account(proposal, proposal_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: mint_accounts(&fixture, proposal),
+        data: green_stablecoin::instruction::Mint { amount: 1 }.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.admin]).await;
+
+    assert_custom_error(result, OracleError::StaleOraclePrice.into());
+}
+
+// Set the collateral targets through proposal 0, executed by a RiskManager
+async fn set_collateral_targets(
+    target_usdt_bps: u16,
+    target_re_bps: u16,
+) -> (BanksClient, Fixture, std::result::Result<(), BanksClientError>) {
+    let (mut program_test, fixture) = setup();
+    let risk_manager = Keypair::new();
+    let (risk_manager_role, risk_manager_role_data) =
+        role_account(Role::RiskManager, risk_manager.pubkey());
+    program_test.add_account(risk_manager_role, risk_manager_role_data);
+    let action = PrivilegedAction::SetCollateralTargets {
+        target_usdt_bps,
+        target_re_bps,
+        ramp_duration: green_stablecoin::MIN_COLLATERAL_RAMP_DURATION,
+    };
+    let (proposal, proposal_data) = proposal_account(0, action, fixture.admin.pubkey());
+    program_test.add_account(proposal, proposal_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::SetCollateral {
+            config: fixture.config,
+            admin_set: fixture.admin_set,
+            proposal,
+            risk_manager: risk_manager.pubkey(),
+            risk_manager_role,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::SetCollateralTargets {
+            target_usdt_bps,
+            target_re_bps,
+            ramp_duration: green_stablecoin::MIN_COLLATERAL_RAMP_DURATION,
+        }
+        .data(),
+    };
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&risk_manager]).await;
+    (banks_client, fixture, result)
+}
+
+#[tokio::test]
+async fn test_set_collateral_targets_rejects_undercollateralized_split() {
+    // 60% USDT + 30% real estate would leave a tenth of the supply unbacked
+    let (_, _, result) = set_collateral_targets(6_000, 3_000).await;
+
+    assert_custom_error(result, StablecoinError::InvalidCollateralTargets.into());
+}
+
+#[tokio::test]
+async fn test_set_collateral_targets_starts_ramp_through_approved_proposal() {
+    let (mut banks_client, fixture, result) = set_collateral_targets(8_000, 2_000).await;
+    result.unwrap();
+
+    // The targets ramp from 70/30 towards 80/20 over the minimum ramp duration
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.target_usdt_bps, 8_000);
+    assert_eq!(config.target_re_bps, 2_000);
+    assert_eq!(config.ramp_start_usdt_bps, green_stablecoin::DEFAULT_TARGET_USDT_BPS);
+    assert_eq!(config.ramp_start_re_bps, green_stablecoin::DEFAULT_TARGET_RE_BPS);
+    assert_eq!(
+        config.ramp_end_time - config.ramp_start_time,
+        green_stablecoin::MIN_COLLATERAL_RAMP_DURATION
+    );
+}
+
+#[tokio::test]
+async fn test_mint_redeem_fees_capped_at_max_fee() {
+    let (mut program_test, fixture) = setup();
+    let fee_manager = Keypair::new();
+    let (fee_manager_role, role_assignment) = role_account(Role::FeeManager, fee_manager.pubkey());
+    program_test.add_account(fee_manager_role, role_assignment);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::SetFee {
+            config: fixture.config,
+            fee_manager: fee_manager.pubkey(),
+            fee_manager_role,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::SetMintRedeemFees {
+            mint_fee_bps: 10,
+            redeem_fee_bps: green_stablecoin::MAX_FEE_BPS + 1,
+        }
+        .data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fee_manager]).await;
+
+    assert_custom_error(result, StablecoinError::InvalidFee.into());
+}
+
+#[tokio::test]
+async fn test_min_fee_capped_at_one_token() {
+    let (mut program_test, fixture) = setup();
+    let fee_manager = Keypair::new();
+    let (fee_manager_role, role_assignment) = role_account(Role::FeeManager, fee_manager.pubkey());
+    program_test.add_account(fee_manager_role, role_assignment);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::SetFee {
+            config: fixture.config,
+            fee_manager: fee_manager.pubkey(),
+            fee_manager_role,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::SetFee {
+            fee_bps: 50,
+            min_fee: 1_000_001,
+            fee_tiers: vec![],
+        }
+        .data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fee_manager]).await;
+
+    assert_custom_error(result, StablecoinError::InvalidFee.into());
+}
+
+// A USDT PSM with a 10 bps tin fee and the given debt ceiling
+fn psm_account(fixture: &Fixture, debt_ceiling: u64) -> (Pubkey, Account) {
+    let (psm, _) = Pubkey::find_program_address(&[b"psm"], &green_stablecoin::id());
+    let account = program_account(&Psm {
+        tin_bps: 10,
+        tout_bps: 10,
+        debt_ceiling,
+        ..Psm::default()
+    });
+    (psm, account)
+}
+
+fn swap_in_accounts(
+    fixture: &Fixture,
+    psm: Pubkey,
+    attestation: Pubkey,
+    collateral_mint: Pubkey,
+    user_collateral_account: Pubkey,
+) -> Vec<AccountMeta> {
+    green_stablecoin::accounts::SwapIn {
+        config: fixture.config,
+        psm,
+        user: fixture.sender.pubkey(),
+        attestation,
+        user_blacklist: blacklist_address(fixture.sender.pubkey()),
+        collateral_mint,
+        user_collateral_account,
+        collateral_vault: fixture.usdt_vault,
+        mint: fixture.mint,
+        user_token_account: fixture.sender_token_account,
+        revenue_vault: fixture.revenue_vault,
+        mint_authority: fixture.mint_authority,
+        usdt_oracle: fixture.usdt_oracle,
+        token_program: token::ID,
+    }
+    .to_account_metas(None)
+}
+
+#[tokio::test]
+async fn test_psm_swap_in_mints_against_usdt_reserve() {
+    let (mut program_test, fixture) = setup();
+
+    let (psm, psm_data) = psm_account(&fixture, 1_000_000);
+    program_test.add_account(psm, psm_data);
+    let user_usdt_account = Pubkey::new_unique();
+    program_test.add_account(
+        user_usdt_account,
+        token_account(fixture.usdt_mint, fixture.sender.pubkey(), 10_000),
+    );
+    let (attestation, attestation_data) = attestation_account(fixture.sender.pubkey(), i64::MAX);
+    program_test.add_account(attestation, attestation_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: swap_in_accounts(&fixture, psm, attestation, fixture.usdt_mint, user_usdt_account),
+        data: green_stablecoin::instruction::SwapIn {
+            collateral_amount: 10_000,
+        }
+        .data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender])
+        .await
+        .unwrap();
+
+    // The USDT lands in the reserve vault and counts towards the USDT reserve
+    assert_eq!(token_balance(&mut banks_client, fixture.usdt_vault).await, 1_010_000);
+    assert_eq!(token_balance(&mut banks_client, fixture.sender_token_account).await, 1_009_990);
+    assert_eq!(token_balance(&mut banks_client, fixture.revenue_vault).await, 10);
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.usdt_reserve, 1_010_000);
+    assert_eq!(config.total_supply, 1_010_000);
+    let state: Psm = program_state(&mut banks_client, psm).await;
+    assert_eq!(state.debt, 10_000);
+    assert_eq!(state.fee_revenue, 10);
+}
+
+#[tokio::test]
+async fn test_psm_swap_in_rejected_above_debt_ceiling() {
+    let (mut program_test, fixture) = setup();
+
+    let (psm, psm_data) = psm_account(&fixture, 1_000);
+    program_test.add_account(psm, psm_data);
+    let user_usdt_account = Pubkey::new_unique();
+    program_test.add_account(
+        user_usdt_account,
+        token_account(fixture.usdt_mint, fixture.sender.pubkey(), 10_000),
+    );
+    let (attestation, attestation_data) = attestation_account(fixture.sender.pubkey(), i64::MAX);
+    program_test.add_account(attestation, attestation_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: swap_in_accounts(&fixture, psm, attestation, fixture.usdt_mint, user_usdt_account),
+        data: green_stablecoin::instruction::SwapIn {
+            collateral_amount: 10_000,
+        }
+        .data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender]).await;
+
+    assert_custom_error(result, PsmError::DebtCeilingExceeded.into());
+}
+
+#[tokio::test]
+async fn test_psm_swap_in_halted_when_usdt_depegs() {
+    let (mut program_test, fixture) = setup();
+
+    // USDT at $0.97 is below the 98% floor, so the PSM stops minting with deposits
+    program_test.add_account(fixture.usdt_oracle, price_account(97_000_000, -8));
+    let (psm, psm_data) = psm_account(&fixture, 1_000_000);
+    program_test.add_account(psm, psm_data);
+    let user_usdt_account = Pubkey::new_unique();
+    program_test.add_account(
+        user_usdt_account,
+        token_account(fixture.usdt_mint, fixture.sender.pubkey(), 10_000),
+    );
+    let (attestation, attestation_data) = attestation_account(fixture.sender.pubkey(), i64::MAX);
+    program_test.add_account(attestation, attestation_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: swap_in_accounts(&fixture, psm, attestation, fixture.usdt_mint, user_usdt_account),
+        data: green_stablecoin::instruction::SwapIn {
+            collateral_amount: 10_000,
+        }
+        .data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender]).await;
+
+    assert_custom_error(result, OracleError::UsdtDepegged.into());
+}
+
+#[tokio::test]
+async fn test_psm_rejects_collateral_other_than_usdt() {
+    let (mut program_test, fixture) = setup();
+
+    let (psm, psm_data) = psm_account(&fixture, 1_000_000);
+    program_test.add_account(psm, psm_data);
+    let other_mint = Pubkey::new_unique();
+    let user_collateral_account = Pubkey::new_unique();
+    program_test.add_account(other_mint, mint_account(Pubkey::new_unique()));
+    program_test.add_account(
+        user_collateral_account,
+        token_account(other_mint, fixture.sender.pubkey(), 10_000),
+    );
+    let (attestation, attestation_data) = attestation_account(fixture.sender.pubkey(), i64::MAX);
+    program_test.add_account(attestation, attestation_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: swap_in_accounts(&fixture, psm, attestation, other_mint, user_collateral_account),
+        data: green_stablecoin::instruction::SwapIn {
+            collateral_amount: 10_000,
+        }
+        .data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender]).await;
+
+    assert_custom_error(result, PsmError::UnsupportedCollateral.into());
+}
+
+#[tokio::test]
+async fn test_psm_swap_out_limited_to_psm_debt() {
+    let (mut program_test, fixture) = setup();
+
+    // Only 5_000 Establo was issued through the PSM
+    let (psm, _) = Pubkey::find_program_address(&[b"psm"], &green_stablecoin::id());
+    program_test.add_account(
+        psm,
+        program_account(&Psm {
+            tin_bps: 10,
+            tout_bps: 10,
+            debt_ceiling: 1_000_000,
+            debt: 5_000,
+            ..Psm::default()
+        }),
+    );
+    let user_usdt_account = Pubkey::new_unique();
+    program_test.add_account(
+        user_usdt_account,
+        token_account(fixture.usdt_mint, fixture.sender.pubkey(), 0),
+    );
+    let (attestation, attestation_data) = attestation_account(fixture.sender.pubkey(), i64::MAX);
+    program_test.add_account(attestation, attestation_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let swap_out = |amount: u64| Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::SwapOut {
+            config: fixture.config,
+            psm,
+            user: fixture.sender.pubkey(),
+            attestation,
+            user_blacklist: blacklist_address(fixture.sender.pubkey()),
+            collateral_mint: fixture.usdt_mint,
+            user_collateral_account: user_usdt_account,
+            collateral_vault: fixture.usdt_vault,
+            mint: fixture.mint,
+            user_token_account: fixture.sender_token_account,
+            revenue_vault: fixture.revenue_vault,
+            vault_authority: fixture.vault_authority,
+            token_program: token::ID,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::SwapOut { amount }.data(),
+    };
+
+    // Deposits backing the rest of the supply cannot be drained through the PSM
+    let result = process(&mut banks_client, &payer, recent_blockhash, swap_out(10_000), &[&fixture.sender]).await;
+    assert_custom_error(result, PsmError::InsufficientCollateral.into());
+
+    process(&mut banks_client, &payer, recent_blockhash, swap_out(5_000), &[&fixture.sender])
+        .await
+        .unwrap();
+
+    assert_eq!(token_balance(&mut banks_client, user_usdt_account).await, 4_995);
+    assert_eq!(token_balance(&mut banks_client, fixture.revenue_vault).await, 5);
+    let state: Psm = program_state(&mut banks_client, psm).await;
+    assert_eq!(state.debt, 5);
+    assert_eq!(state.fee_revenue, 5);
+}
+
+#[tokio::test]
+async fn test_initialize_psm_through_approved_proposal() {
+    let (mut program_test, fixture) = setup();
+    let program_id = green_stablecoin::id();
+
+    let reserve_manager = Keypair::new();
+    let (reserve_manager_role, reserve_manager_role_data) =
+        role_account(Role::ReserveManager, reserve_manager.pubkey());
+    program_test.add_account(reserve_manager_role, reserve_manager_role_data);
+    program_test.add_account(
+        reserve_manager.pubkey(),
+        Account::new(1_000_000_000, 0, &system_program::ID),
+    );
+    let (proposal, proposal_data) = proposal_account(
+        0,
+        PrivilegedAction::ConfigurePsm {
+            tin_bps: 10,
+            tout_bps: 20,
+            debt_ceiling: 500_000,
+        },
+        fixture.admin.pubkey(),
+    );
+    program_test.add_account(proposal, proposal_data);
+    let (psm, _) = Pubkey::find_program_address(&[b"psm"], &program_id);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let initialize = |debt_ceiling: u64| Instruction {
+        program_id,
+        accounts: green_stablecoin::accounts::InitializePsm {
+            config: fixture.config,
+            admin_set: fixture.admin_set,
+            proposal,
+            psm,
+            reserve_manager: reserve_manager.pubkey(),
+            reserve_manager_role,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::InitializePsm {
+            tin_bps: 10,
+            tout_bps: 20,
+            debt_ceiling,
+        }
+        .data(),
+    };
+
+    // The approval only covers the proposed parameters
+    let result = process(&mut banks_client, &payer, recent_blockhash, initialize(u64::MAX), &[&reserve_manager]).await;
+    assert_custom_error(result, MultisigError::ActionMismatch.into());
+
+    process(&mut banks_client, &payer, recent_blockhash, initialize(500_000), &[&reserve_manager])
+        .await
+        .unwrap();
+
+    let state: Psm = program_state(&mut banks_client, psm).await;
+    assert_eq!(state.tin_bps, 10);
+    assert_eq!(state.tout_bps, 20);
+    assert_eq!(state.debt_ceiling, 500_000);
+}
+
+#[tokio::test]
+async fn test_transfer_pays_fee_to_dao() {
+    let (program_test, fixture) = setup();
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: transfer_accounts(&fixture).to_account_metas(None),
+        data: green_stablecoin::instruction::Transfer { amount: 10_000 }.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender])
+        .await
+        .unwrap();
+
+    // 0.5% of 10,000 goes to the DAO, the rest to the recipient
+    assert_eq!(token_balance(&mut banks_client, fixture.sender_token_account).await, 990_000);
+    assert_eq!(token_balance(&mut banks_client, fixture.recipient_token_account).await, 9_950);
+    assert_eq!(token_balance(&mut banks_client, fixture.dao_token_account).await, 50);
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.dao_contributions, 50);
+}
+
+#[tokio::test]
+async fn test_transfer_from_exempt_sender_carries_no_fee() {
+    let (mut program_test, fixture) = setup();
+
+    let accounts = transfer_accounts(&fixture);
+    program_test.add_account(
+        accounts.sender_fee_exemption,
+        program_account(&FeeExemption {
+            wallet: fixture.sender.pubkey(),
+        }),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: accounts.to_account_metas(None),
+        data: green_stablecoin::instruction::Transfer { amount: 10_000 }.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender])
+        .await
+        .unwrap();
+
+    assert_eq!(token_balance(&mut banks_client, fixture.recipient_token_account).await, 10_000);
+    assert_eq!(token_balance(&mut banks_client, fixture.dao_token_account).await, 0);
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.dao_contributions, 0);
+}
+
+#[tokio::test]
+async fn test_add_fee_exemption_by_fee_manager() {
+    let (mut program_test, fixture) = setup();
+
+    let fee_manager = Keypair::new();
+    program_test.add_account(
+        fee_manager.pubkey(),
+        Account::new(1_000_000_000, 0, &system_program::ID),
+    );
+    let (fee_manager_role, fee_manager_role_data) = role_account(Role::FeeManager, fee_manager.pubkey());
+    program_test.add_account(fee_manager_role, fee_manager_role_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    // Exempt the sender, then check the transfer it makes carries no fee
+    let accounts = transfer_accounts(&fixture);
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::AddFeeExemption {
+            config: fixture.config,
+            fee_manager: fee_manager.pubkey(),
+            fee_manager_role,
+            fee_exemption: accounts.sender_fee_exemption,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::AddFeeExemption {
+            wallet: fixture.sender.pubkey(),
+        }
+        .data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&fee_manager])
+        .await
+        .unwrap();
+
+    let exemption: FeeExemption = program_state(&mut banks_client, accounts.sender_fee_exemption).await;
+    assert_eq!(exemption.wallet, fixture.sender.pubkey());
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: accounts.to_account_metas(None),
+        data: green_stablecoin::instruction::Transfer { amount: 10_000 }.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender])
+        .await
+        .unwrap();
+    assert_eq!(token_balance(&mut banks_client, fixture.recipient_token_account).await, 10_000);
+    assert_eq!(token_balance(&mut banks_client, fixture.dao_token_account).await, 0);
+}
+
+#[tokio::test]
+async fn test_transfer_splits_fee_across_beneficiaries() {
+    let (mut program_test, fixture) = setup();
+
+    // Reforestation takes 60% of the fee, clean water the remaining 40%
+    let reforestation = Pubkey::new_unique();
+    let clean_water = Pubkey::new_unique();
+    program_test.add_account(reforestation, token_account(fixture.mint, Pubkey::new_unique(), 0));
+    program_test.add_account(clean_water, token_account(fixture.mint, Pubkey::new_unique(), 0));
+    program_test.add_account(
+        fixture.fee_split,
+        program_account(&FeeSplit {
+            beneficiaries: vec![
+                FeeBeneficiary {
+                    token_account: reforestation,
+                    weight_bps: 6_000,
+                    contributions: 0,
+                },
+                FeeBeneficiary {
+                    token_account: clean_water,
+                    weight_bps: 4_000,
+                    contributions: 0,
+                },
+            ],
+        }),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let mut accounts = transfer_accounts(&fixture).to_account_metas(None);
+    accounts.push(AccountMeta::new(reforestation, false));
+    accounts.push(AccountMeta::new(clean_water, false));
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts,
+        data: green_stablecoin::instruction::Transfer { amount: 10_000 }.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender])
+        .await
+        .unwrap();
+
+    assert_eq!(token_balance(&mut banks_client, fixture.recipient_token_account).await, 9_950);
+    assert_eq!(token_balance(&mut banks_client, reforestation).await, 30);
+    assert_eq!(token_balance(&mut banks_client, clean_water).await, 20);
+    assert_eq!(token_balance(&mut banks_client, fixture.dao_token_account).await, 0);
+    let fee_split: FeeSplit = program_state(&mut banks_client, fixture.fee_split).await;
+    assert_eq!(fee_split.beneficiaries[0].contributions, 30);
+    assert_eq!(fee_split.beneficiaries[1].contributions, 20);
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.dao_contributions, 50);
+}
+
+#[tokio::test]
+async fn test_migrate_mint_authority_hands_mint_to_pda() {
+    let (mut program_test, fixture) = setup();
+
+    // A mint deployed before the PDA authority existed, still controlled by a deployer key
+    let deployer = Keypair::new();
+    program_test.add_account(fixture.mint, mint_account(deployer.pubkey()));
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::MigrateMintAuthority {
+            config: fixture.config,
+            admin: fixture.admin.pubkey(),
+            mint: fixture.mint,
+            current_authority: deployer.pubkey(),
+            mint_authority: fixture.mint_authority,
+            token_program: token::ID,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::MigrateMintAuthority {}.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.admin, &deployer])
+        .await
+        .unwrap();
+
+    let mint = banks_client.get_account(fixture.mint).await.unwrap().unwrap();
+    let mint = spl_token::state::Mint::unpack(&mint.data).unwrap();
+    assert_eq!(mint.mint_authority, COption::Some(fixture.mint_authority));
+}
+
+#[tokio::test]
+async fn test_admin_handover_takes_effect_on_accept() {
+    let (program_test, fixture) = setup();
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let new_admin = Keypair::new();
+    let propose = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::ProposeAdmin {
+            config: fixture.config,
+            admin: fixture.admin.pubkey(),
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::ProposeAdmin {
+            new_admin: new_admin.pubkey(),
+        }
+        .data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, propose, &[&fixture.admin])
+        .await
+        .unwrap();
+
+    // Proposing alone hands nothing over
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.admin, fixture.admin.pubkey());
+    assert_eq!(config.pending_admin, new_admin.pubkey());
+
+    let accept = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::AcceptAdmin {
+            config: fixture.config,
+            pending_admin: new_admin.pubkey(),
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::AcceptAdmin {}.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, accept, &[&new_admin])
+        .await
+        .unwrap();
+
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.admin, new_admin.pubkey());
+    assert_eq!(config.pending_admin, Pubkey::default());
+}
+
+#[tokio::test]
+async fn test_execute_dao_account_after_timelock() {
+    let (mut program_test, fixture) = setup();
+
+    // A DAO account change proposed long enough ago for its timelock to have elapsed
+    let new_dao_token_account = Pubkey::new_unique();
+    program_test.add_account(
+        new_dao_token_account,
+        token_account(fixture.mint, Pubkey::new_unique(), 0),
+    );
+    program_test.add_account(
+        fixture.config,
+        program_account(&Config {
+            pending_dao_account: new_dao_token_account,
+            dao_update_eta: 1,
+            ..base_config(&fixture)
+        }),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::ExecuteDaoAccount {
+            config: fixture.config,
+            admin: fixture.admin.pubkey(),
+            new_dao_token_account,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::ExecuteDaoAccount {}.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.admin])
+        .await
+        .unwrap();
+
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.dao_token_account, new_dao_token_account);
+    assert_eq!(config.pending_dao_account, Pubkey::default());
+    assert_eq!(config.dao_update_eta, 0);
+}
+
+#[tokio::test]
+async fn test_propose_then_cancel_dao_account() {
+    let (mut program_test, fixture) = setup();
+
+    let new_dao_token_account = Pubkey::new_unique();
+    program_test.add_account(
+        new_dao_token_account,
+        token_account(fixture.mint, Pubkey::new_unique(), 0),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+    let clock: Clock = banks_client.get_sysvar().await.unwrap();
+
+    let propose = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::ProposeDaoAccount {
+            config: fixture.config,
+            admin: fixture.admin.pubkey(),
+            new_dao_token_account,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::ProposeDaoAccount {}.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, propose, &[&fixture.admin])
+        .await
+        .unwrap();
+
+    // The change waits out the timelock and the DAO account is untouched until then
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.dao_token_account, fixture.dao_token_account);
+    assert_eq!(config.pending_dao_account, new_dao_token_account);
+    assert!(config.dao_update_eta >= clock.unix_timestamp + green_stablecoin::DEFAULT_DAO_TIMELOCK);
+
+    let execute = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::ExecuteDaoAccount {
+            config: fixture.config,
+            admin: fixture.admin.pubkey(),
+            new_dao_token_account,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::ExecuteDaoAccount {}.data(),
+    };
+    let result = process(&mut banks_client, &payer, recent_blockhash, execute, &[&fixture.admin]).await;
+    assert_custom_error(result, StablecoinError::DaoTimelockNotElapsed.into());
+
+    let cancel = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::UpdateDao {
+            config: fixture.config,
+            admin: fixture.admin.pubkey(),
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::CancelDaoAccount {}.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, cancel, &[&fixture.admin])
+        .await
+        .unwrap();
+
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.dao_token_account, fixture.dao_token_account);
+    assert_eq!(config.pending_dao_account, Pubkey::default());
+    assert_eq!(config.dao_update_eta, 0);
+}
+
+#[tokio::test]
+async fn test_propose_dao_account_rejected_while_pending() {
+    let (mut program_test, fixture) = setup();
+
+    // A change is already waiting out its timelock
+    let pending_dao_account = Pubkey::new_unique();
+    let new_dao_token_account = Pubkey::new_unique();
+    program_test.add_account(
+        new_dao_token_account,
+        token_account(fixture.mint, Pubkey::new_unique(), 0),
+    );
+    program_test.add_account(
+        fixture.config,
+        program_account(&Config {
+            pending_dao_account,
+            dao_update_eta: i64::MAX,
+            ..base_config(&fixture)
+        }),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::ProposeDaoAccount {
+            config: fixture.config,
+            admin: fixture.admin.pubkey(),
+            new_dao_token_account,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::ProposeDaoAccount {}.data(),
+    };
+
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.admin]).await;
+
+    assert_custom_error(result, StablecoinError::DaoUpdatePending.into());
+}
+
+#[tokio::test]
+async fn test_snapshot_reserves_starts_hash_chain() {
+    let (program_test, fixture) = setup();
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+    let program_id = green_stablecoin::id();
+
+    let (snapshot, _) = Pubkey::find_program_address(
+        &[b"reserve_snapshot", 0u64.to_le_bytes().as_ref()],
+        &program_id,
+    );
+    let ix = Instruction {
+        program_id,
+        accounts: green_stablecoin::accounts::SnapshotReserves {
+            config: fixture.config,
+            usdt_mint: fixture.usdt_mint,
+            usdt_vault: fixture.usdt_vault,
+            snapshot,
+            payer: payer.pubkey(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::SnapshotReserves {}.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[])
+        .await
+        .unwrap();
+
+    let snapshot: ReserveSnapshot = program_state(&mut banks_client, snapshot).await;
+    assert_eq!(snapshot.index, 0);
+    assert_eq!(snapshot.total_supply, 1_000_000);
+    assert_eq!(snapshot.usdt_vault_balance, 1_000_000);
+    assert_eq!(snapshot.prev_hash, [0u8; 32]);
+    assert_eq!(snapshot.hash, proof_of_reserves::snapshot_hash(&snapshot));
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.snapshot_count, 1);
+    assert_eq!(config.last_snapshot_hash, snapshot.hash);
+}
+
+#[tokio::test]
+async fn test_deposit_and_mint_backed_by_usdt_alone() {
+    let (mut program_test, fixture) = setup();
+
+    // No real estate synced yet: USDT alone fully backs the supply
+    program_test.add_account(
+        fixture.config,
+        program_account(&Config {
+            real_estate_value: 0,
+            haircut_real_estate_value: 0,
+            ..base_config(&fixture)
+        }),
+    );
+    let user_usdt_account = Pubkey::new_unique();
+    program_test.add_account(
+        user_usdt_account,
+        token_account(fixture.usdt_mint, fixture.sender.pubkey(), 10_000),
+    );
+    let (attestation, attestation_data) = attestation_account(fixture.sender.pubkey(), i64::MAX);
+    program_test.add_account(attestation, attestation_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: deposit_accounts(&fixture, attestation, user_usdt_account),
+        data: green_stablecoin::instruction::DepositAndMint { usdt_amount: 10_000 }.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender])
+        .await
+        .unwrap();
+
+    assert_eq!(token_balance(&mut banks_client, user_usdt_account).await, 0);
+    assert_eq!(token_balance(&mut banks_client, fixture.usdt_vault).await, 1_010_000);
+    assert_eq!(token_balance(&mut banks_client, fixture.sender_token_account).await, 1_010_000);
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.usdt_reserve, 1_010_000);
+    assert_eq!(config.total_supply, 1_010_000);
+}
+
+#[tokio::test]
+async fn test_get_reserves_after_deposit_and_valuation() {
+    let (mut program_test, fixture) = setup();
+    let program_id = green_stablecoin::id();
+
+    // No real estate counted yet, and a freshly listed residential property awaiting appraisal
+    program_test.add_account(
+        fixture.config,
+        program_account(&Config {
+            real_estate_value: 0,
+            haircut_real_estate_value: 0,
+            ..base_config(&fixture)
+        }),
+    );
+    let (marketplace, marketplace_data) = marketplace_account(&fixture, [0; 5]);
+    program_test.add_account(marketplace, marketplace_data);
+    let property_mint = Pubkey::new_unique();
+    let (property, _) =
+        Pubkey::find_program_address(&[b"property", property_mint.as_ref()], &program_id);
+    program_test.add_account(
+        property,
+        program_account(&RealEstateProperty {
+            owner: Pubkey::new_unique(),
+            mint: property_mint,
+            value: 500_000,
+            initial_value: 500_000,
+            last_valuation_date: 0,
+            location: "Zurich".to_string(),
+            details: "Two-bedroom flat".to_string(),
+            status: AssetStatus::Pending,
+            liquidation_threshold: 90,
+            class: PropertyClass::Residential,
+        }),
+    );
+    let appraiser = Keypair::new();
+    let (appraiser_role, appraiser_role_data) = role_account(Role::Appraiser, appraiser.pubkey());
+    program_test.add_account(appraiser_role, appraiser_role_data);
+    let reserve_manager = Keypair::new();
+    let (reserve_manager_role, reserve_manager_role_data) =
+        role_account(Role::ReserveManager, reserve_manager.pubkey());
+    program_test.add_account(reserve_manager_role, reserve_manager_role_data);
+    let user_usdt_account = Pubkey::new_unique();
+    program_test.add_account(
+        user_usdt_account,
+        token_account(fixture.usdt_mint, fixture.sender.pubkey(), 10_000),
+    );
+    let (attestation, attestation_data) = attestation_account(fixture.sender.pubkey(), i64::MAX);
+    program_test.add_account(attestation, attestation_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    // Deposit 10,000 USDT, appraise the property at 500,000 and sync the reserves
+    let deposit = Instruction {
+        program_id,
+        accounts: deposit_accounts(&fixture, attestation, user_usdt_account),
+        data: green_stablecoin::instruction::DepositAndMint { usdt_amount: 10_000 }.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, deposit, &[&fixture.sender])
+        .await
+        .unwrap();
+    let appraise = Instruction {
+        program_id,
+        accounts: green_stablecoin::accounts::UpdateValuation {
+            marketplace,
+            property,
+            appraiser: appraiser.pubkey(),
+            appraiser_role,
+            stablecoin_config: fixture.config,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::UpdateValuation { new_value: 500_000 }.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, appraise, &[&appraiser])
+        .await
+        .unwrap();
+    let sync = Instruction {
+        program_id,
+        accounts: green_stablecoin::accounts::SyncReserves {
+            config: fixture.config,
+            reserve_manager: reserve_manager.pubkey(),
+            reserve_manager_role,
+            usdt_mint: fixture.usdt_mint,
+            usdt_vault: fixture.usdt_vault,
+            marketplace,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::SyncReserves {}.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, sync, &[&reserve_manager])
+        .await
+        .unwrap();
+
+    let status = reserve_status(&mut banks_client, &payer, recent_blockhash, &fixture).await;
+
+    assert_eq!(status.total_supply, 1_010_000);
+    assert_eq!(status.usdt_reserve, 1_010_000);
+    assert_eq!(status.usdt_vault_balance, 1_010_000);
+    assert_eq!(status.real_estate_value, 500_000);
+    // Residential properties count at 80%
+    assert_eq!(status.haircut_real_estate_value, 400_000);
+    assert_eq!(status.collateral_ratio_bps, (1_010_000 + 400_000) * 10_000 / 1_010_000);
+}
+
+#[tokio::test]
+async fn test_deposit_and_mint_rejected_below_usdt_target() {
+    let (mut program_test, fixture) = setup();
+
+    // Plenty of real estate cannot stand in for USDT below its 70% floor
+    program_test.add_account(
+        fixture.config,
+        program_account(&Config {
+            usdt_reserve: 600_000,
+            real_estate_value: 2_000_000,
+            haircut_real_estate_value: 2_000_000,
+            ..base_config(&fixture)
+        }),
+    );
+    let user_usdt_account = Pubkey::new_unique();
+    program_test.add_account(
+        user_usdt_account,
+        token_account(fixture.usdt_mint, fixture.sender.pubkey(), 10_000),
+    );
+    let (attestation, attestation_data) = attestation_account(fixture.sender.pubkey(), i64::MAX);
+    program_test.add_account(attestation, attestation_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: deposit_accounts(&fixture, attestation, user_usdt_account),
+        data: green_stablecoin::instruction::DepositAndMint { usdt_amount: 10_000 }.data(),
+    };
+    let result = process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender]).await;
+
+    assert_custom_error(result, StablecoinError::InsufficientReserves.into());
+}
+
+#[tokio::test]
+async fn test_redeem_pays_usdt_from_reserve_vault() {
+    let (mut program_test, fixture) = setup();
+
+    let holder_usdt_account = Pubkey::new_unique();
+    program_test.add_account(
+        holder_usdt_account,
+        token_account(fixture.usdt_mint, fixture.sender.pubkey(), 0),
+    );
+    let (attestation, attestation_data) = attestation_account(fixture.sender.pubkey(), i64::MAX);
+    program_test.add_account(attestation, attestation_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: redeem_accounts(&fixture, attestation, holder_usdt_account),
+        data: green_stablecoin::instruction::Redeem { amount: 10_000 }.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender])
+        .await
+        .unwrap();
+
+    // The Establo is burned and the same amount of USDT leaves the vault
+    assert_eq!(token_balance(&mut banks_client, fixture.sender_token_account).await, 990_000);
+    assert_eq!(token_balance(&mut banks_client, holder_usdt_account).await, 10_000);
+    assert_eq!(token_balance(&mut banks_client, fixture.usdt_vault).await, 990_000);
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.usdt_reserve, 990_000);
+    assert_eq!(config.total_supply, 990_000);
+}
+
+#[tokio::test]
+async fn test_deposit_then_redeem_round_trip() {
+    let (mut program_test, fixture) = setup();
+
+    // A wallet holding only USDT deposits, then redeems everything it minted
+    let user_usdt_account = Pubkey::new_unique();
+    program_test.add_account(
+        user_usdt_account,
+        token_account(fixture.usdt_mint, fixture.sender.pubkey(), 25_000),
+    );
+    program_test.add_account(
+        fixture.sender_token_account,
+        token_account(fixture.mint, fixture.sender.pubkey(), 0),
+    );
+    let (attestation, attestation_data) = attestation_account(fixture.sender.pubkey(), i64::MAX);
+    program_test.add_account(attestation, attestation_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: deposit_accounts(&fixture, attestation, user_usdt_account),
+        data: green_stablecoin::instruction::DepositAndMint { usdt_amount: 25_000 }.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender])
+        .await
+        .unwrap();
+    assert_eq!(token_balance(&mut banks_client, fixture.sender_token_account).await, 25_000);
+    assert_eq!(token_balance(&mut banks_client, fixture.usdt_vault).await, 1_025_000);
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: redeem_accounts(&fixture, attestation, user_usdt_account),
+        data: green_stablecoin::instruction::Redeem { amount: 25_000 }.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender])
+        .await
+        .unwrap();
+
+    assert_eq!(token_balance(&mut banks_client, fixture.sender_token_account).await, 0);
+    assert_eq!(token_balance(&mut banks_client, user_usdt_account).await, 25_000);
+    assert_eq!(token_balance(&mut banks_client, fixture.usdt_vault).await, 1_000_000);
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.usdt_reserve, 1_000_000);
+    assert_eq!(config.total_supply, 1_000_000);
+}
+
+#[tokio::test]
+async fn test_migrate_config_upgrades_legacy_layout() {
+    let program_id = green_stablecoin::id();
+    let mut program_test = ProgramTest::new(
+        "green_stablecoin",
+        program_id,
+        processor!(green_stablecoin::entry),
+    );
+
+    // A config written by the first release, rent-exempt only for its old size
+    let admin = Keypair::new();
+    let usdt_mint = Pubkey::new_unique();
+    let dao_token_account = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let usdt_oracle = Pubkey::new_unique();
+    let (config, _) = Pubkey::find_program_address(&[b"config"], &program_id);
+    let (fee_split, _) = Pubkey::find_program_address(&[b"fee_split"], &program_id);
+    let (revenue_vault, _) = Pubkey::find_program_address(&[b"revenue_vault"], &program_id);
+    let (vault_authority, _) = Pubkey::find_program_address(&[b"vault_authority"], &program_id);
+    let mut data = Config::discriminator().to_vec();
+    (admin.pubkey(), usdt_mint, dao_token_account, mint, 6u8, 500u64, 700_000u64, 300_000u64)
+        .serialize(&mut data)
+        .unwrap();
+    assert_eq!(data.len(), green_stablecoin::LEGACY_CONFIG_SPACE);
+    program_test.add_account(
+        config,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    program_test.add_account(mint, mint_account(Pubkey::new_unique()));
+    program_test.add_account(usdt_oracle, price_account(100_000_000, -8));
+    program_test.add_account(
+        admin.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id,
+        accounts: green_stablecoin::accounts::MigrateConfig {
+            config,
+            fee_split,
+            admin: admin.pubkey(),
+            mint,
+            usdt_oracle,
+            revenue_vault,
+            vault_authority,
+            system_program: system_program::id(),
+            token_program: token::ID,
+            rent: sysvar::rent::id(),
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::MigrateConfig {}.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&admin])
+        .await
+        .unwrap();
+
+    // Legacy fields carry over, new fields take the defaults and reserves wait for a sync
+    let account = banks_client.get_account(config).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), green_stablecoin::CONFIG_SPACE);
+    let migrated = Config::try_deserialize(&mut account.data.as_ref()).unwrap();
+    assert_eq!(migrated.admin, admin.pubkey());
+    assert_eq!(migrated.usdt_mint, usdt_mint);
+    assert_eq!(migrated.dao_token_account, dao_token_account);
+    assert_eq!(migrated.mint, mint);
+    assert_eq!(migrated.dao_contributions, 500);
+    assert_eq!(migrated.total_supply, 1_000_000);
+    assert_eq!(migrated.usdt_reserve, 0);
+    assert_eq!(migrated.real_estate_value, 0);
+    assert_eq!(migrated.fee_bps, green_stablecoin::DEFAULT_FEE_BPS);
+    assert_eq!(migrated.usdt_oracle, usdt_oracle);
+    let fee_split: FeeSplit = program_state(&mut banks_client, fee_split).await;
+    assert!(fee_split.beneficiaries.is_empty());
+    assert_eq!(token_balance(&mut banks_client, revenue_vault).await, 0);
+}
+
+#[tokio::test]
+async fn test_deposit_collects_mint_fee_into_revenue_vault() {
+    let (mut program_test, fixture) = setup();
+
+    // A 1% mint fee
+    program_test.add_account(
+        fixture.config,
+        program_account(&Config {
+            mint_fee_bps: 100,
+            ..base_config(&fixture)
+        }),
+    );
+    let user_usdt_account = Pubkey::new_unique();
+    program_test.add_account(
+        user_usdt_account,
+        token_account(fixture.usdt_mint, fixture.sender.pubkey(), 10_000),
+    );
+    let (attestation, attestation_data) = attestation_account(fixture.sender.pubkey(), i64::MAX);
+    program_test.add_account(attestation, attestation_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let ix = Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: deposit_accounts(&fixture, attestation, user_usdt_account),
+        data: green_stablecoin::instruction::DepositAndMint { usdt_amount: 10_000 }.data(),
+    };
+    process(&mut banks_client, &payer, recent_blockhash, ix, &[&fixture.sender])
+        .await
+        .unwrap();
+
+    // The full deposit is backed; 1% of the minted Establo goes to the protocol
+    assert_eq!(token_balance(&mut banks_client, fixture.sender_token_account).await, 1_009_900);
+    assert_eq!(token_balance(&mut banks_client, fixture.revenue_vault).await, 100);
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.mint_fee_revenue, 100);
+    assert_eq!(config.total_supply, 1_010_000);
+}
+
+#[tokio::test]
+async fn test_withdraw_revenue_through_approved_proposal() {
+    let (mut program_test, fixture) = setup();
+
+    let treasury = Pubkey::new_unique();
+    let fee_manager = Keypair::new();
+    let (fee_manager_role, fee_manager_role_data) =
+        role_account(Role::FeeManager, fee_manager.pubkey());
+    program_test.add_account(fee_manager_role, fee_manager_role_data);
+    program_test.add_account(treasury, token_account(fixture.mint, Pubkey::new_unique(), 0));
+    program_test.add_account(
+        fixture.revenue_vault,
+        token_account(fixture.mint, fixture.vault_authority, 500),
+    );
+    let (proposal, proposal_data) = proposal_account(
+        0,
+        PrivilegedAction::WithdrawRevenue {
+            destination: treasury,
+            amount: 300,
+        },
+        fixture.admin.pubkey(),
+    );
+    program_test.add_account(proposal, proposal_data);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let withdraw = |amount: u64| Instruction {
+        program_id: green_stablecoin::id(),
+        accounts: green_stablecoin::accounts::WithdrawRevenue {
+            config: fixture.config,
+            admin_set: fixture.admin_set,
+            proposal,
+            fee_manager: fee_manager.pubkey(),
+            fee_manager_role,
+            revenue_vault: fixture.revenue_vault,
+            destination: treasury,
+            vault_authority: fixture.vault_authority,
+            token_program: token::ID,
+        }
+        .to_account_metas(None),
+        data: green_stablecoin::instruction::WithdrawRevenue { amount }.data(),
+    };
+
+    // The approval only covers the proposed amount
+    let result = process(&mut banks_client, &payer, recent_blockhash, withdraw(500), &[&fee_manager]).await;
+    assert_custom_error(result, MultisigError::ActionMismatch.into());
+
+    process(&mut banks_client, &payer, recent_blockhash, withdraw(300), &[&fee_manager])
+        .await
+        .unwrap();
+
+    assert_eq!(token_balance(&mut banks_client, treasury).await, 300);
+    assert_eq!(token_balance(&mut banks_client, fixture.revenue_vault).await, 200);
+    let config: Config = program_state(&mut banks_client, fixture.config).await;
+    assert_eq!(config.revenue_withdrawn, 300);
+}
@@ -3,6 +3,8 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo, Burn, Transfer,
 
 declare_id!("4qeLVUWckMZkhxAMKT92qGmj9Zr7f5rooN16hqmwyrQU");
 
+pub const MAX_FEE_BPS: u16 = 500; // 5% protocol maximum
+
 #[program]
 pub mod green_stablecoin {
     use super::*;
@@ -14,7 +16,15 @@ pub mod green_stablecoin {
         real_estate_cid: String,
         real_estate_value: u64,
         decimals: u8,
+        target_usdt_bps: u16,
+        target_re_bps: u16,
+        fee_bps: u16,
     ) -> Result<()> {
+        require!(
+            collateral_targets_valid(target_usdt_bps, target_re_bps),
+            ErrorCode::InvalidCollateralTargets
+        );
+        require!(fee_bps <= MAX_FEE_BPS, ErrorCode::InvalidFee);
         let config = &mut ctx.accounts.config;
         config.admin = ctx.accounts.admin.key();
         config.usdt_mint = usdt_mint;
@@ -24,6 +34,9 @@ pub mod green_stablecoin {
         config.real_estate_value = real_estate_value;
         config.mint = ctx.accounts.mint.key();
         config.decimals = decimals;
+        config.target_usdt_bps = target_usdt_bps;
+        config.target_re_bps = target_re_bps;
+        config.fee_bps = fee_bps;
 
         // Set mint authority to the PDA
         let cpi_accounts = SetAuthority {
@@ -43,17 +56,9 @@ pub mod green_stablecoin {
 pub fn mint(ctx: Context<MintStablecoin>, amount: u64) -> Result<()> {
     let config = &mut ctx.accounts.config;
 
-    // Calculate required USDT and real estate backing
-    let required_usdt = amount
-        .checked_mul(70)
-        .ok_or(ErrorCode::Overflow)?
-        .checked_div(100)
-        .ok_or(ErrorCode::Overflow)?;
-    let required_real_estate = amount
-        .checked_mul(30)
-        .ok_or(ErrorCode::Overflow)?
-        .checked_div(100)
-        .ok_or(ErrorCode::Overflow)?;
+    // Calculate required USDT and real estate backing from the configured targets
+    let required_usdt = (amount as u128 * config.target_usdt_bps as u128 / 10_000) as u64;
+    let required_real_estate = (amount as u128 * config.target_re_bps as u128 / 10_000) as u64;
 
     // Check USDT reserve
     let usdt_reserve = ctx.accounts.usdt_reserve.amount;
@@ -129,12 +134,8 @@ pub fn mint(ctx: Context<MintStablecoin>, amount: u64) -> Result<()> {
     pub fn transfer(ctx: Context<StablecoinTransfer>, amount: u64) -> Result<()> {
         let config = &ctx.accounts.config;
 
-        // Calculate fee (0.5% = 50 basis points)
-        let fee = amount
-            .checked_mul(50)
-            .ok_or(ErrorCode::Overflow)?
-            .checked_div(10000)
-            .ok_or(ErrorCode::Overflow)?;
+        // Calculate fee from the configured rate, at most MAX_FEE_BPS
+        let fee = (amount as u128 * config.fee_bps as u128 / 10_000) as u64;
         let amount_after_fee = amount
             .checked_sub(fee)
             .ok_or(ErrorCode::InsufficientAmount)?;
@@ -210,6 +211,34 @@ pub fn mint(ctx: Context<MintStablecoin>, amount: u64) -> Result<()> {
         config.real_estate_value = new_value;
         Ok(())
     }
+
+    pub fn set_collateral_targets(
+        ctx: Context<SetCollateralTargets>,
+        target_usdt_bps: u16,
+        target_re_bps: u16,
+    ) -> Result<()> {
+        require!(
+            collateral_targets_valid(target_usdt_bps, target_re_bps),
+            ErrorCode::InvalidCollateralTargets
+        );
+        let config = &mut ctx.accounts.config;
+        config.target_usdt_bps = target_usdt_bps;
+        config.target_re_bps = target_re_bps;
+        Ok(())
+    }
+
+    pub fn set_fee(ctx: Context<SetFee>, fee_bps: u16) -> Result<()> {
+        require!(fee_bps <= MAX_FEE_BPS, ErrorCode::InvalidFee);
+        ctx.accounts.config.fee_bps = fee_bps;
+        Ok(())
+    }
+}
+
+// Each leg is at most 100% and together they must at least fully back the supply
+fn collateral_targets_valid(target_usdt_bps: u16, target_re_bps: u16) -> bool {
+    target_usdt_bps <= 10_000
+        && target_re_bps <= 10_000
+        && target_usdt_bps as u32 + target_re_bps as u32 >= 10_000
 }
 
 #[derive(Accounts)]
@@ -217,7 +246,7 @@ pub struct Initialize<'info> {
     #[account(
         init,
         payer = admin,
-        space = 8 + 32 + 32 + 32 + 8 + 4 + 200 + 8 + 1,
+        space = 8 + 32 + 32 + 32 + 8 + 4 + 200 + 8 + 1 + 2 + 2 + 2,
         seeds = [b"config"],
         bump
     )]
@@ -300,6 +329,20 @@ pub struct UpdateRealEstate<'info> {
     pub admin: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct SetCollateralTargets<'info> {
+    #[account(mut, has_one = admin)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetFee<'info> {
+    #[account(mut, has_one = admin)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+}
+
 #[account]
 pub struct Config {
     pub admin: Pubkey,
@@ -311,6 +354,9 @@ pub struct Config {
     pub mint: Pubkey,
     pub decimals: u8,
     pub total_supply: u64, // New field to track total supply
+    pub target_usdt_bps: u16, // USDT backing target (basis points of supply)
+    pub target_re_bps: u16,   // Real estate backing target (basis points of supply)
+    pub fee_bps: u16,         // Green DAO transfer fee (basis points)
 }
 
 #[event]
@@ -341,6 +387,10 @@ pub enum ErrorCode {
     Unauthorized,
     #[msg("Invalid DAO account.")]
     InvalidDaoAccount,
+    #[msg("Collateral targets must each be at most 100% and together at least 100%.")]
+    InvalidCollateralTargets,
+    #[msg("Fee exceeds the protocol maximum.")]
+    InvalidFee,
 }
 
 